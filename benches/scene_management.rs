@@ -2,6 +2,7 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "rendering")] {
         use criterion::{criterion_group, criterion_main, Criterion};
         use livesplit_core::{
+            component,
             layout::{self, Layout},
             rendering::{
                 PathBuilder, ResourceAllocator, SceneManager, Label, FontKind, SharedOwnership,
@@ -13,7 +14,7 @@ cfg_if::cfg_if! {
         use std::fs;
 
         criterion_main!(benches);
-        criterion_group!(benches, default, subsplits_layout);
+        criterion_group!(benches, default, subsplits_layout, timer_only);
 
         struct Dummy;
 
@@ -116,6 +117,23 @@ cfg_if::cfg_if! {
             });
         }
 
+        fn timer_only(c: &mut Criterion) {
+            let mut timer = Timer::new(create_run(&["A", "B", "C", "D"])).unwrap();
+            let mut layout = Layout::new();
+            layout.push(component::timer::Component::new());
+
+            start_run(&mut timer);
+            make_progress_run_with_splits_opt(&mut timer, &[Some(5.0)]);
+
+            let state = layout.state(&timer.snapshot());
+
+            let mut manager = SceneManager::new(Dummy);
+
+            c.bench_function("Scene Management (Timer Only)", move |b| {
+                b.iter(|| manager.update_scene(Dummy, (300.0, 100.0), &state))
+            });
+        }
+
         fn file(path: &str) -> String {
             fs::read_to_string(path).unwrap()
         }
@@ -2,16 +2,22 @@
 //! Additionally a value is returned that indicates whether the delta value is a
 //! live delta. A live delta indicates that the value is actively changing at
 //! the moment. This may be the case when the current attempt is slower than the
-//! comparison at the current split.
+//! comparison at the current split. If the timer has a transient comparison
+//! installed, it is preferred over the comparison provided.
 
-use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase};
+use crate::{analysis, timing::Snapshot, Time, TimeSpan, TimerPhase, TimingMethod};
 
 /// Calculates the delta of the current attempt to the comparison provided.
 /// Additionally a value is returned that indicates whether the delta value is a
 /// live delta. A live delta indicates that the value is actively changing at
 /// the moment. This may be the case when the current attempt is slower than the
-/// comparison at the current split.
+/// comparison at the current split. If the timer has a transient comparison
+/// installed, it is preferred over the comparison provided.
 pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, bool) {
+    if let Some(splits) = timer.transient_comparison() {
+        return calculate_transient(timer, splits);
+    }
+
     let timing_method = timer.current_timing_method();
     let last_segment = timer.run().segments().last().unwrap();
 
@@ -47,3 +53,55 @@ pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, b
 
     (time, use_live_delta)
 }
+
+fn calculate_transient(timer: &Snapshot<'_>, splits: &[Time]) -> (Option<TimeSpan>, bool) {
+    let timing_method = timer.current_timing_method();
+    let comparison_time = |index: usize| -> Option<TimeSpan> { splits.get(index)?[timing_method] };
+
+    let mut use_live_delta = false;
+
+    let time = match timer.current_phase() {
+        TimerPhase::Running | TimerPhase::Paused => {
+            let current_split_index = timer.current_split_index().unwrap();
+            let mut delta =
+                last_transient_delta(timer, current_split_index, comparison_time, timing_method);
+
+            catch! {
+                let live_delta = timer.current_time()[timing_method]?
+                    - comparison_time(current_split_index)?;
+
+                if live_delta > delta.unwrap_or_default() {
+                    delta = Some(live_delta);
+                    use_live_delta = true;
+                }
+            };
+
+            delta
+        }
+        TimerPhase::Ended => catch! {
+            let last_index = timer.run().len() - 1;
+            timer.run().segment(last_index).split_time()[timing_method]?
+                - comparison_time(last_index)?
+        },
+        TimerPhase::NotRunning => None,
+    };
+
+    (time, use_live_delta)
+}
+
+fn last_transient_delta(
+    timer: &Snapshot<'_>,
+    segment_index: usize,
+    comparison_time: impl Fn(usize) -> Option<TimeSpan>,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    timer.run().segments()[..=segment_index]
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, segment)| {
+            catch! {
+                segment.split_time()[method]? - comparison_time(index)?
+            }
+        })
+}
@@ -283,11 +283,11 @@ impl AutoSplitTimer for Timer {
     }
 
     fn split(&mut self) {
-        self.0.write().unwrap().split()
+        self.0.write().unwrap().split();
     }
 
     fn reset(&mut self) {
-        self.0.write().unwrap().reset(true)
+        self.0.write().unwrap().reset(true);
     }
 
     fn set_game_time(&mut self, time: time::Duration) {
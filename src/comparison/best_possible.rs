@@ -0,0 +1,48 @@
+//! Defines the Comparison Generator for the Best Possible time of a
+//! [`Run`](crate::Run). Each split time is the sum of the Best Segment times up
+//! to and including that segment, so the final split time is the best possible
+//! time based on the current Best Segments.
+
+use super::ComparisonGenerator;
+use crate::{Attempt, Segment, TimeSpan, TimingMethod};
+
+/// Defines the Comparison Generator for the Best Possible time of a
+/// [`Run`](crate::Run). Each split time is the sum of the Best Segment times up
+/// to and including that segment, so the final split time is the best possible
+/// time based on the current Best Segments. Unlike the
+/// [`Best Segments`](super::best_segments) comparison, this doesn't consider
+/// combined segments from the Segment History, so it always follows the Best
+/// Segment times exactly. Segments without a Best Segment time are skipped,
+/// they don't get a split time and the following segments only sum up the
+/// Best Segment times that exist.
+///
+/// This comparison is not part of the
+/// [`default_generators`](super::default_generators). The
+/// [`Timer`](crate::Timer) adds it to a Run on demand when switching to it.
+#[derive(Copy, Clone, Debug)]
+pub struct BestPossible;
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Best Possible";
+/// The name of this comparison.
+pub const NAME: &str = "Best Possible";
+
+impl ComparisonGenerator for BestPossible {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        for method in TimingMethod::all() {
+            let mut total = TimeSpan::zero();
+            for segment in segments.iter_mut() {
+                let split_time = segment.best_segment_time()[method].map(|best_segment| {
+                    total += best_segment;
+                    total
+                });
+                segment.comparison_mut(NAME)[method] = split_time;
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ mod tests;
 
 pub mod average_segments;
 pub mod balanced_pb;
+pub mod best_possible;
 pub mod best_segments;
 pub mod best_split_times;
 pub mod goal;
@@ -16,9 +17,9 @@ pub mod none;
 pub mod worst_segments;
 
 pub use self::{
-    average_segments::AverageSegments, balanced_pb::BalancedPB, best_segments::BestSegments,
-    best_split_times::BestSplitTimes, latest_run::LatestRun, median_segments::MedianSegments,
-    none::None, worst_segments::WorstSegments,
+    average_segments::AverageSegments, balanced_pb::BalancedPB, best_possible::BestPossible,
+    best_segments::BestSegments, best_split_times::BestSplitTimes, latest_run::LatestRun,
+    median_segments::MedianSegments, none::None, worst_segments::WorstSegments,
 };
 
 use crate::{platform::prelude::*, Attempt, Segment, Timer};
@@ -109,6 +110,7 @@ pub fn try_shorten(comparison: &str) -> Option<&'static str> {
         average_segments::NAME => average_segments::SHORT_NAME,
         median_segments::NAME => median_segments::SHORT_NAME,
         balanced_pb::NAME => balanced_pb::SHORT_NAME,
+        best_possible::NAME => best_possible::SHORT_NAME,
         best_segments::NAME => best_segments::SHORT_NAME,
         best_split_times::NAME => best_split_times::SHORT_NAME,
         latest_run::NAME => latest_run::SHORT_NAME,
@@ -0,0 +1,25 @@
+use crate::comparison::{
+    best_possible::{BestPossible, NAME},
+    ComparisonGenerator,
+};
+use crate::{Segment, TimeSpan};
+
+#[test]
+fn skips_missing_best_segments() {
+    let s = TimeSpan::from_seconds;
+
+    let mut segments = [
+        Segment::new("First"),
+        Segment::new("Second"),
+        Segment::new("Third"),
+    ];
+    segments[0].best_segment_time_mut().real_time = Some(s(1.0));
+    segments[2].best_segment_time_mut().real_time = Some(s(2.0));
+
+    BestPossible.generate(&mut segments, &[]);
+
+    assert_eq!(segments[0].comparison(NAME).real_time, Some(s(1.0)));
+    assert_eq!(segments[1].comparison(NAME).real_time, None);
+    assert_eq!(segments[2].comparison(NAME).real_time, Some(s(3.0)));
+    assert_eq!(segments[2].comparison(NAME).game_time, None);
+}
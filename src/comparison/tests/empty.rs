@@ -14,6 +14,11 @@ fn balanced_pb() {
     test(comparison::BalancedPB);
 }
 
+#[test]
+fn best_possible() {
+    test(comparison::BestPossible);
+}
+
 #[test]
 fn best_segments() {
     test(comparison::BestSegments);
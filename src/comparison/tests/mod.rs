@@ -1,4 +1,5 @@
 mod average;
 mod balanced_pb;
+mod best_possible;
 mod empty;
 mod median;
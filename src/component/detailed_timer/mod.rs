@@ -198,7 +198,7 @@ impl Component {
             .settings
             .timer
             .timing_method
-            .unwrap_or_else(|| timer.current_timing_method());
+            .unwrap_or_else(|| timer.display_method());
 
         let run = timer.run();
 
@@ -1,5 +1,5 @@
 use super::{Component, Settings};
-use crate::{GeneralLayoutSettings, Run, Segment, Timer};
+use crate::{GeneralLayoutSettings, Run, Segment, TimeSpan, Timer, TimingMethod};
 
 fn prepare() -> (Timer, Component, GeneralLayoutSettings) {
     let mut run = Run::new();
@@ -184,3 +184,31 @@ fn shows_icon_again_when_remounting() {
         .filter(|s| !s.is_empty())
         .is_some());
 }
+
+#[test]
+fn shows_the_display_method_on_the_timer() {
+    let (mut timer, mut component, layout_settings) = prepare();
+
+    timer.start();
+    timer.pause_game_time();
+    timer.set_game_time(TimeSpan::from_seconds(83.0));
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    assert_eq!(
+        component
+            .state(&timer.snapshot(), &layout_settings)
+            .timer
+            .time,
+        "1:23",
+    );
+
+    timer.set_display_method(Some(TimingMethod::RealTime));
+
+    assert_eq!(
+        component
+            .state(&timer.snapshot(), &layout_settings)
+            .timer
+            .time,
+        "0",
+    );
+}
@@ -1,13 +1,18 @@
 //! Provides the state for key value based components. Examples of these
 //! components include the Previous Segment and the Possible Time Save
 //! components. They all share the same visual appearance and thus use the same
-//! state object representation.
+//! state object representation. Simple statistics about the timer can also
+//! be visualized directly by sourcing the value from a [`KeyValueSource`].
 
 use crate::{
+    analysis::{sum_of_segments::calculate_best, total_playtime},
     platform::prelude::*,
     settings::{Color, Gradient, SemanticColor},
+    timing::formatter::{Days, Regular, TimeFormatter},
+    Timer,
 };
 use alloc::borrow::Cow;
+use core::fmt::Write;
 use serde::{Deserialize, Serialize};
 
 /// The state object describes the information to visualize for a key value
@@ -49,6 +54,79 @@ impl State {
     }
 }
 
+/// A metric derived from the timer that a key value based state can source its
+/// key and value from. This allows visualizing simple statistics without a
+/// dedicated component for each of them. The value is resolved whenever the
+/// state is updated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyValueSource {
+    /// The total amount of time that the current category has been played
+    /// for.
+    TotalPlaytime,
+    /// The amount of attempts that have been started for the run.
+    AttemptCount,
+    /// The Sum of Best Segments for the current timing method.
+    SumOfBest,
+}
+
+impl KeyValueSource {
+    /// Accesses the key that is shown for the source.
+    pub const fn key(self) -> &'static str {
+        match self {
+            KeyValueSource::TotalPlaytime => "Total Playtime",
+            KeyValueSource::AttemptCount => "Attempts",
+            KeyValueSource::SumOfBest => "Sum of Best Segments",
+        }
+    }
+
+    /// Updates the key and the value of the state provided based on the
+    /// timer. All the visual settings of the state are left untouched.
+    pub fn update_state(self, state: &mut State, timer: &Timer) {
+        state.key.clear();
+        state.key.push_str(self.key());
+
+        state.key_abbreviations.clear();
+        state.value.clear();
+
+        match self {
+            KeyValueSource::TotalPlaytime => {
+                let total_playtime = total_playtime::calculate(timer);
+                let _ = write!(state.value, "{}", Days::new().format(total_playtime));
+                state.key_abbreviations.push("Playtime".into());
+            }
+            KeyValueSource::AttemptCount => {
+                let _ = write!(state.value, "{}", timer.run().attempt_count());
+            }
+            KeyValueSource::SumOfBest => {
+                let time = calculate_best(
+                    timer.run().segments(),
+                    false,
+                    true,
+                    timer.current_timing_method(),
+                );
+                let _ = write!(state.value, "{}", Regular::new().format(time));
+                state.key_abbreviations.push("Sum of Best".into());
+                state.key_abbreviations.push("SoB".into());
+            }
+        }
+
+        state.semantic_color = Default::default();
+        state.updates_frequently =
+            self == KeyValueSource::TotalPlaytime && timer.current_phase().is_running();
+    }
+
+    /// Calculates a key value based state for the source based on the timer
+    /// provided.
+    pub fn state(self, timer: &Timer) -> State {
+        let mut state = State {
+            background: DEFAULT_GRADIENT,
+            ..Default::default()
+        };
+        self.update_state(&mut state, timer);
+        state
+    }
+}
+
 pub(super) const DEFAULT_GRADIENT: Gradient = Gradient::Vertical(
     Color {
         red: 1.0,
@@ -39,7 +39,10 @@ pub struct Settings {
     /// The background shown behind the component.
     pub background: Gradient,
     /// The comparison chosen. Uses the Timer's current comparison if set to
-    /// `None`.
+    /// `None`. Setting this to the Best Segments comparison shows the previous
+    /// segment compared to its best segment time, regardless of the comparison
+    /// the Timer compares against. The comparison is then included in the
+    /// key of the state.
     pub comparison_override: Option<String>,
     /// Specifies whether to display the name of the component and its value in
     /// two separate rows.
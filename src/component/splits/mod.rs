@@ -7,6 +7,7 @@
 
 use crate::{
     platform::prelude::*,
+    run::Segment,
     settings::{
         self, CachedImageId, Color, Field, Gradient, ImageData, ListGradient, SettingsDescription,
         Value,
@@ -15,7 +16,10 @@ use crate::{
     util::{Clear, ClearVec},
     GeneralLayoutSettings,
 };
-use core::cmp::{max, min};
+use core::{
+    cmp::{max, min},
+    ops::Range,
+};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -98,6 +102,12 @@ pub struct Settings {
     pub delta_drop_decimals: bool,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// Specifies whether to group the segments into sub-splits. Segments whose
+    /// name starts with a `-` are sub-splits of the group that is ended by the
+    /// next segment whose name doesn't. The group's name can be specified in
+    /// curly braces at the beginning of the name of the segment ending it, such
+    /// as `{Forest} Boss`. Each group gets a header row showing its name.
+    pub show_group_headers: bool,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
@@ -120,6 +130,10 @@ pub struct SplitState {
     /// there can be a scrolling window, showing only a subset of segments. Each
     /// index is guaranteed to be unique.
     pub index: usize,
+    /// The index of the segment that ends the group of sub-splits this segment
+    /// belongs to. This is based on the index in the run, just like `index`.
+    /// If the segment doesn't belong to a group, this is `None`.
+    pub parent_group: Option<usize>,
 }
 
 impl Clear for SplitState {
@@ -129,6 +143,24 @@ impl Clear for SplitState {
     }
 }
 
+/// The state object that describes a group of sub-splits. A header row is
+/// supposed to be visualized in front of the first visible segment of the
+/// group.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupState {
+    /// The name of the group.
+    pub name: String,
+    /// The index of the segment that ends the group. This is what the
+    /// `parent_group` of the segments in the group refers to.
+    pub segment_index: usize,
+}
+
+impl Clear for GroupState {
+    fn clear(&mut self) {
+        self.name.clear();
+    }
+}
+
 /// Describes the icon to be shown for a certain segment. This is provided
 /// whenever a segment is first shown or whenever its icon changes. If
 /// necessary, you may remount this component to reset the component into a
@@ -156,6 +188,8 @@ pub struct State {
     pub column_labels: Option<ClearVec<String>>,
     /// The list of all the segments to visualize.
     pub splits: ClearVec<SplitState>,
+    /// The groups of sub-splits that the segments to visualize belong to.
+    pub groups: ClearVec<GroupState>,
     /// This list describes all the icon changes that happened. Each time a
     /// segment is first shown or its icon changes, the new icon is provided in
     /// this list. If necessary, you may remount this component to reset the
@@ -178,6 +212,23 @@ pub struct State {
     /// The gradient to show behind the current segment as an indicator of it
     /// being the current segment.
     pub current_split_gradient: Gradient,
+    /// The range of splits to visualize, based on their position in the list
+    /// of splits. If this is `None`, all the splits are visualized. The range
+    /// is clamped to the splits that are available, so it is allowed to extend
+    /// past the end of the list. This is not modified when the component
+    /// updates its state, so it can be used to only visualize a scrolling
+    /// window of the splits.
+    pub visible_range: Option<Range<usize>>,
+    /// Specifies whether the visible range automatically scrolls along with
+    /// the current split, such that the current split stays centered within
+    /// it. Only the length of the visible range is used if this is enabled.
+    /// This is not modified when the component updates its state.
+    pub auto_scroll: bool,
+    /// The index of the segment the active attempt is currently on, based on
+    /// all the segments of the run. If there's no attempt in progress, this
+    /// is `None`. This is equal to the amount of segments when the attempt is
+    /// finished, but has not been reset.
+    pub current_split_index: Option<usize>,
 }
 
 impl Default for Settings {
@@ -203,6 +254,7 @@ impl Default for Settings {
             delta_time_accuracy: Accuracy::Tenths,
             delta_drop_decimals: true,
             show_column_labels: false,
+            show_group_headers: false,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -229,6 +281,118 @@ impl Default for Settings {
     }
 }
 
+impl State {
+    /// Accesses the splits that are supposed to be visualized. If there is a
+    /// visible range, only the splits within that range are returned.
+    pub fn visible_splits(&self) -> &[SplitState] {
+        let splits: &[SplitState] = &self.splits;
+        match self.scrolled_range() {
+            Some(range) => {
+                let end = range.end.min(splits.len());
+                &splits[range.start.min(end)..end]
+            }
+            None => splits,
+        }
+    }
+
+    /// Calculates the range of splits to visualize. If auto scrolling is
+    /// enabled, the visible range is moved such that the current split is
+    /// centered within it. Once the attempt is finished, the window stays at
+    /// the end of the splits.
+    pub fn scrolled_range(&self) -> Option<Range<usize>> {
+        let range = self.visible_range.clone()?;
+        if !self.auto_scroll {
+            return Some(range);
+        }
+
+        let current_split_index = match self.current_split_index {
+            Some(index) => index,
+            None => return Some(range),
+        };
+
+        // When the attempt is finished, the current split index is past the
+        // last segment, so there's no split marked as the current split. In
+        // that case we center around the last segment that got split instead.
+        let position = match self
+            .splits
+            .iter()
+            .position(|s| s.index == current_split_index)
+            .or_else(|| {
+                self.splits
+                    .iter()
+                    .rposition(|s| s.index < current_split_index)
+            }) {
+            Some(position) => position,
+            None => return Some(range),
+        };
+
+        let len = range.end.saturating_sub(range.start);
+        let start = position
+            .saturating_sub(len / 2)
+            .min(self.splits.len().saturating_sub(len));
+
+        Some(start..start + len)
+    }
+
+    /// Accesses the group of sub-splits with the index of the segment that
+    /// ends it.
+    pub fn group(&self, segment_index: usize) -> Option<&GroupState> {
+        self.groups
+            .iter()
+            .find(|group| group.segment_index == segment_index)
+    }
+
+    /// Counts the group header rows to visualize in front of the visible
+    /// splits. A header is shown whenever a visible split belongs to a
+    /// different group than the split before it.
+    pub fn group_header_count(&self) -> usize {
+        let mut previous_group = None;
+        let mut count = 0;
+        for split in self.visible_splits() {
+            if split.parent_group.is_some() && split.parent_group != previous_group {
+                count += 1;
+            }
+            previous_group = split.parent_group;
+        }
+        count
+    }
+}
+
+fn is_sub_split(segment: &Segment) -> bool {
+    segment.name().starts_with('-')
+}
+
+/// Splits the name of the segment that ends a group into the group's name and
+/// the segment's own name.
+fn split_group_name(name: &str) -> (Option<&str>, &str) {
+    if let Some(rest) = name.strip_prefix('{') {
+        if let Some((group, name)) = rest.split_once('}') {
+            return (Some(group), name.trim_start());
+        }
+    }
+    (None, name)
+}
+
+/// Determines the index of the segment ending the group of sub-splits that the
+/// segment belongs to.
+fn parent_group(segments: &[Segment], index: usize) -> Option<usize> {
+    if is_sub_split(&segments[index]) {
+        Some(
+            segments[index..]
+                .iter()
+                .position(|s| !is_sub_split(s))
+                .map_or(segments.len() - 1, |offset| index + offset),
+        )
+    } else if index
+        .checked_sub(1)
+        .map_or(false, |i| is_sub_split(&segments[i]))
+    {
+        Some(index)
+    } else {
+        None
+    }
+}
+
 #[cfg(feature = "std")]
 impl State {
     /// Encodes the state object's information as JSON.
@@ -361,9 +525,12 @@ impl Component {
             state.column_labels = None;
         }
 
+        let show_group_headers = self.settings.show_group_headers;
+
         let icon_changes = &mut state.icon_changes;
         icon_changes.clear();
 
+        state.groups.clear();
         state.splits.clear();
         for ((i, segment), icon_id) in run
             .segments()
@@ -375,11 +542,33 @@ impl Component {
                 i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
             })
         {
+            let parent_group = if show_group_headers {
+                parent_group(run.segments(), i)
+            } else {
+                None
+            };
+
+            if let Some(group_index) = parent_group {
+                if state.groups.last().map(|g| g.segment_index) != Some(group_index) {
+                    let group = state.groups.push_with(|| GroupState {
+                        name: String::new(),
+                        segment_index: 0,
+                    });
+                    let (group_name, segment_name) =
+                        split_group_name(run.segment(group_index).name());
+                    group
+                        .name
+                        .push_str(group_name.unwrap_or(segment_name.trim_start_matches('-')));
+                    group.segment_index = group_index;
+                }
+            }
+
             let state = state.splits.push_with(|| SplitState {
                 name: String::new(),
                 columns: ClearVec::new(),
                 is_current_split: false,
                 index: 0,
+                parent_group: None,
             });
 
             if let Some(icon_change) = icon_id.update_with(Some(segment.icon())) {
@@ -389,7 +578,12 @@ impl Component {
                 });
             }
 
-            state.name.push_str(segment.name());
+            if parent_group.is_some() {
+                let (_, name) = split_group_name(segment.name());
+                state.name.push_str(name.trim_start_matches('-'));
+            } else {
+                state.name.push_str(segment.name());
+            }
 
             for column in columns {
                 column::update_state(
@@ -412,6 +606,7 @@ impl Component {
 
             state.is_current_split = Some(i) == current_split;
             state.index = i;
+            state.parent_group = parent_group;
         }
 
         if fill_with_blank_space && state.splits.len() < visual_split_count {
@@ -422,9 +617,11 @@ impl Component {
                     columns: ClearVec::new(),
                     is_current_split: false,
                     index: 0,
+                    parent_group: None,
                 });
                 state.is_current_split = false;
                 state.index = (usize::max_value() ^ 1) - 2 * i;
+                state.parent_group = None;
             }
         }
 
@@ -433,6 +630,7 @@ impl Component {
         state.show_final_separator = show_final_separator;
         state.display_two_rows = display_two_rows;
         state.current_split_gradient = self.settings.current_split_gradient;
+        state.current_split_index = current_split;
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -564,6 +762,13 @@ impl Component {
             }
         }
 
+        // This setting got introduced after the columns, so it's placed after
+        // them to keep the indices of all the other settings stable.
+        settings.fields.push(Field::new(
+            "Show Group Headers".into(),
+            self.settings.show_group_headers.into(),
+        ));
+
         settings
     }
 
@@ -637,6 +842,10 @@ impl Component {
                         }
                     }
                 }
+                if index == 0 {
+                    self.settings.show_group_headers = value.into();
+                    return;
+                }
                 panic!("Unsupported Setting Index")
             }
         }
@@ -137,3 +137,186 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+#[test]
+fn visible_range_is_clamped_to_the_splits() {
+    let mut run = Run::new();
+    for name in ["A", "B", "C", "D"] {
+        run.push_segment(Segment::new(name));
+    }
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        ..Default::default()
+    });
+
+    let mut state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(state.visible_splits().len(), 4);
+
+    state.visible_range = Some(1..3);
+    let names = state
+        .visible_splits()
+        .iter()
+        .map(|s| &*s.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["B", "C"]);
+
+    state.visible_range = Some(2..10);
+    assert_eq!(state.visible_splits().len(), 2);
+
+    state.visible_range = Some(6..10);
+    assert!(state.visible_splits().is_empty());
+
+    // Updating the state keeps the visible range intact.
+    component.update_state(&mut state, &timer.snapshot(), &Default::default());
+    assert_eq!(state.visible_range, Some(6..10));
+}
+
+#[test]
+fn auto_scroll_keeps_the_current_split_centered() {
+    let mut run = Run::new();
+    for _ in 0..10 {
+        run.push_segment(Segment::new(""));
+    }
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        ..Default::default()
+    });
+
+    let mut state = component.state(&timer.snapshot(), &Default::default());
+    state.visible_range = Some(0..3);
+    state.auto_scroll = true;
+
+    // Without an attempt in progress, the visible range is used as is.
+    assert_eq!(state.scrolled_range(), Some(0..3));
+
+    timer.start();
+    component.update_state(&mut state, &timer.snapshot(), &Default::default());
+    assert_eq!(state.scrolled_range(), Some(0..3));
+
+    for _ in 0..5 {
+        timer.split();
+    }
+    component.update_state(&mut state, &timer.snapshot(), &Default::default());
+    assert_eq!(state.scrolled_range(), Some(4..7));
+    assert!(state.visible_splits()[1].is_current_split);
+
+    for _ in 0..5 {
+        timer.split();
+    }
+    component.update_state(&mut state, &timer.snapshot(), &Default::default());
+    assert_eq!(state.current_split_index, Some(10));
+    assert_eq!(state.scrolled_range(), Some(7..10));
+    assert_eq!(state.visible_splits().len(), 3);
+}
+
+#[test]
+fn group_headers() {
+    let mut run = Run::new();
+    for name in [
+        "Intro",
+        "-Tree",
+        "-Cave",
+        "{Forest} Boss",
+        "-Gate",
+        "Castle",
+        "Ending",
+    ] {
+        run.push_segment(Segment::new(name));
+    }
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        ..Default::default()
+    });
+
+    // Groups are only formed if the group headers are shown.
+    let mut state = component.state(&timer.snapshot(), &Default::default());
+    assert!(state.groups.is_empty());
+    assert!(state.splits.iter().all(|s| s.parent_group.is_none()));
+    assert_eq!(state.group_header_count(), 0);
+
+    component.settings_mut().show_group_headers = true;
+    component.update_state(&mut state, &timer.snapshot(), &Default::default());
+
+    let splits = state
+        .splits
+        .iter()
+        .map(|s| (&*s.name, s.parent_group))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        splits,
+        [
+            ("Intro", None),
+            ("Tree", Some(3)),
+            ("Cave", Some(3)),
+            ("Boss", Some(3)),
+            ("Gate", Some(5)),
+            ("Castle", Some(5)),
+            ("Ending", None),
+        ]
+    );
+
+    assert_eq!(state.groups.len(), 2);
+    assert_eq!(state.group(3).unwrap().name, "Forest");
+    assert_eq!(state.group(5).unwrap().name, "Castle");
+    assert_eq!(state.group_header_count(), 2);
+
+    // A group that is scrolled partially out of view still gets its header.
+    state.visible_range = Some(2..5);
+    assert_eq!(state.group_header_count(), 2);
+    state.visible_range = Some(6..7);
+    assert_eq!(state.group_header_count(), 0);
+}
+
+#[test]
+fn group_headers_setting_comes_after_the_columns() {
+    let mut component = Component::new();
+    let settings = component.settings_description();
+    assert_eq!(settings.fields[14].text, "Columns");
+    let last = settings.fields.len() - 1;
+    assert_eq!(settings.fields[last].text, "Show Group Headers");
+
+    component.set_value(last, true.into());
+    assert!(component.settings().show_group_headers);
+}
+
+#[test]
+fn timer_builds_splits_state_from_snapshot() {
+    let mut run = Run::new();
+    for name in ["A", "B", "C", "D"] {
+        run.push_segment(Segment::new(name));
+    }
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let settings = Settings {
+        always_show_last_split: false,
+        split_preview_count: 0,
+        visual_split_count: 2,
+        ..Default::default()
+    };
+
+    timer.start();
+    timer.split();
+    timer.split();
+
+    let snapshot = timer.snapshot();
+    let state = timer.build_splits_state(&snapshot, &settings, &layout_settings);
+    let names: Vec<_> = state.splits.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["B", "C"]);
+    assert_eq!(state.current_split_index, Some(2));
+
+    let expected = Component::with_settings(settings).state(&snapshot, &layout_settings);
+    assert_eq!(state.splits.len(), expected.splits.len());
+    for (split, expected) in state.splits.iter().zip(&expected.splits) {
+        assert_eq!(split.name, expected.name);
+        assert_eq!(split.columns.len(), expected.columns.len());
+        for (column, expected) in split.columns.iter().zip(&expected.columns) {
+            assert_eq!(column.value, expected.value);
+        }
+    }
+}
@@ -196,6 +196,10 @@ impl Component {
         layout_settings: &GeneralLayoutSettings,
     ) {
         let method = self
+            .settings
+            .timing_method
+            .unwrap_or_else(|| timer.display_method());
+        let comparison_method = self
             .settings
             .timing_method
             .unwrap_or_else(|| timer.current_timing_method());
@@ -217,26 +221,31 @@ impl Component {
 
             (segment_time, SemanticColor::Default)
         } else {
-            let time = timer.current_time();
-            let time = time[method].or(time.real_time).unwrap_or_default();
+            let current_time = timer.current_time();
+            let time = current_time[method]
+                .or(current_time.real_time)
+                .unwrap_or_default();
+            let comparison_time = current_time[comparison_method]
+                .or(current_time.real_time)
+                .unwrap_or_default();
             let current_comparison = timer.current_comparison();
 
             let semantic_color = match phase {
-                TimerPhase::Running if time >= TimeSpan::zero() => {
+                TimerPhase::Running if comparison_time >= TimeSpan::zero() => {
                     let pb_split_time = timer
                         .current_split()
                         .unwrap()
-                        .comparison(current_comparison)[method];
+                        .comparison(current_comparison)[comparison_method];
 
                     if let Some(pb_split_time) = pb_split_time {
                         split_color(
                             timer,
-                            Some(time - pb_split_time),
+                            Some(comparison_time - pb_split_time),
                             timer.current_split_index().unwrap(),
                             true,
                             false,
                             current_comparison,
-                            method,
+                            comparison_method,
                         )
                         .or(SemanticColor::AheadGainingTime)
                     } else {
@@ -250,9 +259,9 @@ impl Component {
                         .segments()
                         .last()
                         .unwrap()
-                        .comparison(current_comparison)[method];
+                        .comparison(current_comparison)[comparison_method];
 
-                    if pb_time.map_or(true, |t| time < t) {
+                    if pb_time.map_or(true, |t| comparison_time < t) {
                         SemanticColor::PersonalBest
                     } else {
                         SemanticColor::BehindLosingTime
@@ -282,18 +291,25 @@ impl Component {
             .background
             .gradient(not_overwritten_visual_color);
 
+        let format_options = timer.format_options();
+        let time = time.map(|time| format_options.round(time, self.settings.accuracy));
+
         state.time.clear();
         let _ = write!(
             state.time,
             "{}",
-            formatter::Time::with_digits_format(self.settings.digits_format).format(time),
+            formatter::Time::with_digits_format(
+                format_options.resolve_digits_format(self.settings.digits_format)
+            )
+            .format(time),
         );
 
         state.fraction.clear();
         let _ = write!(
             state.fraction,
             "{}",
-            formatter::Fraction::with_accuracy(self.settings.accuracy).format(time),
+            formatter::Fraction::with_format_options(self.settings.accuracy, format_options)
+                .format(time),
         );
 
         state.updates_frequently = phase.is_running() && time.is_some();
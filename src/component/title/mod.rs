@@ -1,7 +1,7 @@
 //! Provides the Title Component and relevant types for using it. The Title
 //! Component is a component that shows the name of the game and the category
-//! that is being run. Additionally, the game icon, the attempt count, and the
-//! total number of finished runs can be shown.
+//! that is being run. Additionally, the game icon, the attempt count, the
+//! total number of finished runs and the date of the attempt can be shown.
 
 use crate::{
     platform::prelude::*,
@@ -21,7 +21,7 @@ mod tests;
 
 /// The Title Component is a component that shows the name of the game and the
 /// category that is being run. Additionally, the game icon, the attempt count,
-/// and the total number of finished runs can be shown.
+/// the total number of finished runs and the date of the attempt can be shown.
 #[derive(Default, Clone)]
 pub struct Component {
     icon_id: CachedImageId,
@@ -68,6 +68,10 @@ pub struct Settings {
     /// The category name can be extended by additional information. This
     /// extends it by additional variables provided by the run's metadata.
     pub show_variables: bool,
+    /// Specifies whether the date the current attempt started at should be
+    /// shown. If there's no attempt in progress, the date of the most recent
+    /// attempt is shown instead.
+    pub show_attempt_date: bool,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -103,6 +107,10 @@ pub struct State {
     /// The amount of total attempts. If `None` is specified, the amount of
     /// total attempts isn't supposed to be shown.
     pub attempts: Option<u32>,
+    /// The date the attempt started at, formatted as `YYYY-MM-DD` in UTC. If
+    /// `None` is specified, the date isn't supposed to be shown.
+    #[serde(default)]
+    pub attempt_date: Option<String>,
 }
 
 impl Default for Settings {
@@ -123,6 +131,7 @@ impl Default for Settings {
             show_region: false,
             show_platform: false,
             show_variables: true,
+            show_attempt_date: false,
         }
     }
 }
@@ -194,6 +203,19 @@ impl Component {
             None
         };
 
+        let attempt_date = if self.settings.show_attempt_date {
+            timer
+                .attempt_started()
+                .or_else(|| run.attempt_history().last()?.started())
+                .map(|started| {
+                    let mut date = String::new();
+                    let _ = write!(date, "{}", started.time.date());
+                    date
+                })
+        } else {
+            None
+        };
+
         let game_icon = Some(run.game_icon()).filter(|_| self.settings.display_game_icon);
         let icon_change = self.icon_id.update_with(game_icon).map(Into::into);
 
@@ -341,6 +363,7 @@ impl Component {
         state.icon_change = icon_change;
         state.finished_runs = finished_runs;
         state.attempts = attempts;
+        state.attempt_date = attempt_date;
         state.is_centered = is_centered;
     }
 
@@ -391,6 +414,10 @@ impl Component {
             Field::new("Show Region".into(), self.settings.show_region.into()),
             Field::new("Show Platform".into(), self.settings.show_platform.into()),
             Field::new("Show Variables".into(), self.settings.show_variables.into()),
+            Field::new(
+                "Show Attempt Date".into(),
+                self.settings.show_attempt_date.into(),
+            ),
         ])
     }
 
@@ -415,6 +442,7 @@ impl Component {
             9 => self.settings.show_region = value.into(),
             10 => self.settings.show_platform = value.into(),
             11 => self.settings.show_variables = value.into(),
+            12 => self.settings.show_attempt_date = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
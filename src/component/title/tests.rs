@@ -28,3 +28,27 @@ fn finished_runs_and_attempt_count() {
     assert_eq!(component.state(&timer).finished_runs, Some(1));
     assert_eq!(component.state(&timer).attempts, Some(1));
 }
+
+#[test]
+fn attempt_date() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new(""));
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        show_attempt_date: true,
+        ..Default::default()
+    });
+
+    assert_eq!(component.state(&timer).attempt_date, None);
+
+    timer.start();
+    let date = crate::AtomicDateTime::now().time.date().to_string();
+    assert_eq!(component.state(&timer).attempt_date, Some(date.clone()));
+
+    timer.reset(true);
+    assert_eq!(component.state(&timer).attempt_date, Some(date));
+
+    component.settings_mut().show_attempt_date = false;
+    assert_eq!(component.state(&timer).attempt_date, None);
+}
@@ -64,7 +64,9 @@ impl Action {
     fn callback(self, timer: SharedTimer) -> Box<dyn FnMut() + Send + 'static> {
         match self {
             Action::Split => Box::new(move || timer.write().unwrap().split_or_start()),
-            Action::Reset => Box::new(move || timer.write().unwrap().reset(true)),
+            Action::Reset => Box::new(move || {
+                timer.write().unwrap().reset(true);
+            }),
             Action::Undo => Box::new(move || timer.write().unwrap().undo_split()),
             Action::Skip => Box::new(move || timer.write().unwrap().skip_split()),
             Action::Pause => Box::new(move || timer.write().unwrap().toggle_pause_or_start()),
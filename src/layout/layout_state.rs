@@ -29,6 +29,17 @@ pub struct LayoutState {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// Overrides the width of each component in a horizontal layout, so that
+    /// all the components have the same width instead of a width based on
+    /// their contents. The width is specified in the same units as the
+    /// component heights, where a component in a horizontal layout is about
+    /// 1.7 units tall. The components lay out their contents within that
+    /// width, so text that doesn't fit gets abbreviated or cut off rather than
+    /// the component growing. This is not modified by the layout and is meant
+    /// to be set by the host. Vertical layouts are not affected, as all their
+    /// components already share the same width.
+    #[serde(default)]
+    pub fixed_component_width: Option<f32>,
 }
 
 #[cfg(feature = "std")]
@@ -70,7 +70,17 @@ impl<I, L> Cache<I, L> {
 }
 
 pub fn layout_width(layout: &LayoutState) -> f32 {
-    layout.components.iter().map(width).sum()
+    layout
+        .components
+        .iter()
+        .map(|component| width_in_layout(layout, component))
+        .sum()
+}
+
+pub fn width_in_layout(layout: &LayoutState, component: &ComponentState) -> f32 {
+    layout
+        .fixed_component_width
+        .unwrap_or_else(|| width(component))
 }
 
 pub fn layout_height(layout: &LayoutState) -> f32 {
@@ -85,10 +95,15 @@ pub fn width(component: &ComponentState) -> f32 {
         ComponentState::KeyValue(_) => 6.0,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
-            let column_count = 2.0; // FIXME: Not always 2.
+            let visible_splits = state.visible_splits();
+            let column_count = visible_splits
+                .iter()
+                .map(|split| split.columns.len())
+                .max()
+                .unwrap_or_default() as f32;
             let column_width = 2.75; // FIXME: Not always 2.75; difficult to calculate without a renderer.
             let split_width = 2.0 + column_count * column_width;
-            state.splits.len() as f32 * split_width
+            visible_splits.len() as f32 * split_width
         }
         ComponentState::Text(_) => 6.0,
         ComponentState::Timer(_) => 8.25,
@@ -110,7 +125,7 @@ pub fn height(component: &ComponentState) -> f32 {
         }
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
-            state.splits.len() as f32
+            state.visible_splits().len() as f32
                 * if state.display_two_rows {
                     TWO_ROW_HEIGHT
                 } else {
@@ -121,6 +136,7 @@ pub fn height(component: &ComponentState) -> f32 {
                 } else {
                     0.0
                 }
+                + state.group_header_count() as f32 * DEFAULT_COMPONENT_HEIGHT
         }
         ComponentState::Text(state) => {
             if state.display_two_rows {
@@ -24,6 +24,7 @@ pub struct Cache<I, L> {
     column_labels: Vec<CachedLabel<L>>,
     column_width_label: CachedLabel<L>,
     column_label_widths: Vec<f32>,
+    group_headers: Vec<CachedLabel<L>>,
 }
 
 struct SplitCache<L> {
@@ -48,6 +49,7 @@ impl<I, L> Cache<I, L> {
             column_labels: Vec::new(),
             column_width_label: CachedLabel::new(),
             column_label_widths: Vec::new(),
+            group_headers: Vec::new(),
         }
     }
 }
@@ -84,9 +86,12 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
 
     let vertical_padding = vertical_padding(split_height);
 
+    let splits = component.visible_splits();
+
     let (split_width, (delta_x, delta_y), separator_pos, split_background_bottom_right, icon_y) =
         if layout_state.direction == LayoutDirection::Horizontal {
-            let split_width = width / component.splits.len() as f32;
+            // The visible range may be empty, so avoid dividing by zero.
+            let split_width = width / splits.len().max(1) as f32;
             (
                 split_width,
                 (split_width, 0.0),
@@ -155,12 +160,42 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         PADDING
     };
 
+    cache.splits.resize_with(splits.len(), SplitCache::new);
     cache
-        .splits
-        .resize_with(component.splits.len(), SplitCache::new);
+        .group_headers
+        .resize_with(component.group_header_count(), CachedLabel::new);
+
+    let mut group_headers = cache.group_headers.iter_mut();
+    let mut previous_group = None;
+
+    for (i, (split, split_cache)) in splits.iter().zip(&mut cache.splits).enumerate() {
+        if layout_state.direction == LayoutDirection::Vertical
+            && split.parent_group.is_some()
+            && split.parent_group != previous_group
+        {
+            if let (Some(group), Some(header_cache)) = (
+                split.parent_group.and_then(|index| component.group(index)),
+                group_headers.next(),
+            ) {
+                context.render_text_ellipsis(
+                    &group.name,
+                    header_cache,
+                    [PADDING, TEXT_ALIGN_TOP],
+                    DEFAULT_TEXT_SIZE,
+                    text_color,
+                    width - PADDING,
+                );
+                context.render_rectangle(
+                    [0.0, DEFAULT_COMPONENT_HEIGHT - THIN_SEPARATOR_THICKNESS],
+                    [width, DEFAULT_COMPONENT_HEIGHT],
+                    &Gradient::Plain(layout_state.thin_separators_color),
+                );
+            }
+            context.translate(0.0, DEFAULT_COMPONENT_HEIGHT);
+        }
+        previous_group = split.parent_group;
 
-    for (i, (split, split_cache)) in component.splits.iter().zip(&mut cache.splits).enumerate() {
-        if component.show_thin_separators && i + 1 != component.splits.len() {
+        if component.show_thin_separators && i + 1 != splits.len() {
             context.render_rectangle(
                 separator_pos,
                 [split_width, split_height],
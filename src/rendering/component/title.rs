@@ -22,6 +22,7 @@ pub struct Cache<I, L> {
     game_icon: Option<Icon<I>>,
     attempts: CachedLabel<L>,
     attempts_buffer: String,
+    attempt_date: CachedLabel<L>,
 }
 
 impl<I, L> Cache<I, L> {
@@ -32,6 +33,7 @@ impl<I, L> Cache<I, L> {
             game_icon: None,
             attempts: CachedLabel::new(),
             attempts_buffer: String::new(),
+            attempt_date: CachedLabel::new(),
         }
     }
 }
@@ -89,6 +91,19 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         text_color,
     ) - PADDING;
 
+    let date_end_x = if let Some(date) = &component.attempt_date {
+        context.render_text_right_align(
+            date,
+            &mut cache.attempt_date,
+            Layer::Bottom,
+            [width - PADDING, TEXT_ALIGN_TOP],
+            DEFAULT_TEXT_SIZE,
+            text_color,
+        ) - PADDING
+    } else {
+        width - PADDING
+    };
+
     let (line1_y, line1_end_x) = if !component.line2.is_empty() {
         context.render_abbreviated_text_align(
             component.line2.iter().map(|a| &**a),
@@ -100,9 +115,12 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             component.is_centered,
             text_color,
         );
-        (TEXT_ALIGN_TOP, width - PADDING)
+        (TEXT_ALIGN_TOP, date_end_x)
     } else {
-        (height / 2.0 + TEXT_ALIGN_CENTER, line2_end_x)
+        (
+            height / 2.0 + TEXT_ALIGN_CENTER,
+            line2_end_x.min(date_end_x),
+        )
     };
 
     context.render_abbreviated_text_align(
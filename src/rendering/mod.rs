@@ -164,6 +164,7 @@ pub struct SceneManager<P, I, F, L> {
     next_id: usize,
     cached_size: Option<CachedSize>,
     fonts: FontCache<F>,
+    component_bounds: Vec<[f32; 4]>,
 }
 
 impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager<P, I, F, L> {
@@ -188,6 +189,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             scene: Scene::new(rectangle),
             cached_size: None,
             fonts,
+            component_bounds: Vec::new(),
         }
     }
 
@@ -196,6 +198,17 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         &self.scene
     }
 
+    /// Accesses the bounds of each of the components as they got placed during
+    /// the last update of the [`Scene`]. The bounds are stored as `[x, y,
+    /// width, height]` in the coordinate space of the resolution provided to
+    /// [`update_scene`](Self::update_scene) and are in the same order as the
+    /// components of the [`LayoutState`]. This can be used to figure out which
+    /// component is located at a certain position, such as for interacting
+    /// with the components.
+    pub fn component_bounds(&self) -> &[[f32; 4]] {
+        &self.component_bounds
+    }
+
     /// Updates the [`Scene`] by updating the [`Entities`](Entity) according to
     /// the [`LayoutState`] provided. The [`ResourceAllocator`] is used to
     /// allocate the resources necessary that the [`Entities`](Entity) use. A
@@ -211,6 +224,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         state: &LayoutState,
     ) -> Option<(f32, f32)> {
         self.scene.clear();
+        self.component_bounds.clear();
 
         self.scene
             .set_background(decode_gradient(&state.background));
@@ -229,6 +243,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         };
 
         self.scene.recalculate_if_bottom_layer_changed();
+        self.scene.recalculate_if_top_layer_changed();
 
         new_dimensions
     }
@@ -292,6 +307,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         for (component, cache) in state.components.iter().zip(&mut self.components) {
             let height = component::height(component);
             let dim = [width, height];
+            self.component_bounds.push(context.bounds(dim));
             component::render(cache, &mut context, component, state, dim);
             // We translate the coordinate space to the Component Coordinate
             // Space of the next component by shifting by the height of the
@@ -363,9 +379,10 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         let width_scaling = TWO_ROW_HEIGHT * aspect_ratio / total_width;
 
         for (component, cache) in state.components.iter().zip(&mut self.components) {
-            let width = component::width(component) * width_scaling;
+            let width = component::width_in_layout(state, component) * width_scaling;
             let height = TWO_ROW_HEIGHT;
             let dim = [width, height];
+            self.component_bounds.push(context.bounds(dim));
             component::render(cache, &mut context, component, state, dim);
             // We translate the coordinate space to the Component Coordinate
             // Space of the next component by shifting by the width of the
@@ -463,6 +480,16 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         self.transform = self.transform.pre_translate(x, y);
     }
 
+    fn bounds(&self, [width, height]: Pos) -> [f32; 4] {
+        let transform = &self.transform;
+        [
+            transform.x,
+            transform.y,
+            transform.scale_x * width,
+            transform.scale_y * height,
+        ]
+    }
+
     fn render_rectangle(&mut self, top_left: Pos, bottom_right: Pos, gradient: &Gradient) {
         if let Some(colors) = decode_gradient(gradient) {
             self.backend_render_rectangle(top_left, bottom_right, colors);
@@ -31,15 +31,17 @@ impl Layer {
 /// two [`Layers`](Layer) that are supposed to be composited on top of each
 /// other. The bottom [`Layer`] changes infrequently and doesn't need to be
 /// rerendered for most frames. The top [`Layer`] contains all the per frame
-/// changes and needs to be rerendered for every frame. If however it is empty
-/// and both the bottom layer didn't change, then no new frame needs to be
-/// rendered. While the top [`Layer`] is inherently transparent, the bottom
-/// [`Layer`] has a background that needs to be considered.
+/// changes and needs to be rerendered for every frame. If however neither of
+/// the [`Layers`](Layer) changed, then no new frame needs to be rendered. While
+/// the top [`Layer`] is inherently transparent, the bottom [`Layer`] has a
+/// background that needs to be considered.
 pub struct Scene<P, I, L> {
     rectangle: Handle<P>,
     background: Option<FillShader>,
     bottom_hash: u64,
     bottom_layer_changed: bool,
+    top_hash: u64,
+    top_layer_changed: bool,
     bottom_layer: Vec<Entity<P, I, L>>,
     top_layer: Vec<Entity<P, I, L>>,
 }
@@ -53,6 +55,8 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
             background: None,
             bottom_hash: calculate_hash::<P, I, L>(&None, &[]),
             bottom_layer_changed: false,
+            top_hash: calculate_hash::<P, I, L>(&None, &[]),
+            top_layer_changed: false,
             bottom_layer: Vec::new(),
             top_layer: Vec::new(),
         }
@@ -73,6 +77,20 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.bottom_layer_changed
     }
 
+    /// Check if the scene's top [`Layer`] changed since the previous update of
+    /// the scene.
+    pub const fn top_layer_changed(&self) -> bool {
+        self.top_layer_changed
+    }
+
+    /// Check if any of the scene's [`Layers`](Layer) changed since the
+    /// previous update of the scene. If this returns `false`, the frame
+    /// rendered for the previous update can be kept as is, so rendering can
+    /// be skipped entirely.
+    pub const fn changed(&self) -> bool {
+        self.bottom_layer_changed || self.top_layer_changed
+    }
+
     /// Get a reference to the scene's bottom [`Layer`]. This [`Layer`] is
     /// intended to infrequently change, so it doesn't need to be rerendered
     /// every frame.
@@ -121,6 +139,15 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.bottom_hash = new_hash;
     }
 
+    /// Recalculates the hash of the top [`Layer`] and checks if it changed.
+    /// While the top [`Layer`] is expected to change frequently, it doesn't
+    /// change while the layout is idle, such as when the timer is paused.
+    pub fn recalculate_if_top_layer_changed(&mut self) {
+        let new_hash = calculate_hash(&None, &self.top_layer);
+        self.top_layer_changed = new_hash != self.top_hash;
+        self.top_hash = new_hash;
+    }
+
     /// Accesses the [`Layer`] specified mutably.
     pub fn layer_mut(&mut self, layer: Layer) -> &mut Vec<Entity<P, I, L>> {
         match layer {
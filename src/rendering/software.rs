@@ -236,8 +236,9 @@ impl BorrowedRenderer {
     /// that the layout got resized. In that case it returns the new ideal size.
     /// This is just a hint and can be ignored entirely. The image is always
     /// rendered with the resolution provided. By default the renderer will try
-    /// not to redraw parts of the image that haven't changed. You can force a
-    /// redraw in case the image provided or its contents have changed.
+    /// not to redraw parts of the image that haven't changed. If nothing
+    /// changed at all, the image is not touched. You can force a redraw in
+    /// case the image provided or its contents have changed.
     pub fn render(
         &mut self,
         state: &LayoutState,
@@ -257,6 +258,10 @@ impl BorrowedRenderer {
                 .update_scene(&mut self.allocator, (width as _, height as _), state);
 
         let scene = self.scene_manager.scene();
+        if !force_redraw && !scene.changed() {
+            return new_resolution;
+        }
+
         let rectangle = scene.rectangle();
         let rectangle = rectangle.as_deref().unwrap();
 
@@ -292,6 +297,50 @@ impl BorrowedRenderer {
 
         new_resolution
     }
+
+    /// Renders the layout state just like [`render`](Self::render), but
+    /// additionally pushes the bounds of each component, as `(component_index,
+    /// [x, y, width, height])` in pixels, into the sink provided. These are
+    /// the exact positions the components got rendered at, which is useful for
+    /// building interactive overlays on top of the image.
+    pub fn render_with_bounds(
+        &mut self,
+        state: &LayoutState,
+        image: &mut [u8],
+        dimensions: [u32; 2],
+        stride: u32,
+        force_redraw: bool,
+        bounds: &mut Vec<(usize, [f32; 4])>,
+    ) -> Option<(f32, f32)> {
+        let new_resolution = self.render(state, image, dimensions, stride, force_redraw);
+        bounds.extend(
+            self.scene_manager
+                .component_bounds()
+                .iter()
+                .copied()
+                .enumerate(),
+        );
+        new_resolution
+    }
+
+    /// Returns whether the last call to [`render`](Self::render) produced a
+    /// different frame than the call before it. If it didn't, the image wasn't
+    /// touched, so hosts can skip presenting it again.
+    pub const fn frame_changed(&self) -> bool {
+        self.scene_manager.scene().changed()
+    }
+}
+
+/// Renders the layout state provided into a new image with the chosen
+/// resolution, without the need for a GPU or keeping a renderer around. This is
+/// useful for rendering individual images, such as thumbnails. The image is
+/// returned as RGBA8 encoded pixels (red, green, blue, alpha with each channel
+/// being an u8). If many images are to be rendered, it is more efficient to
+/// reuse a [`Renderer`], as it caches the resources it allocates.
+pub fn render_to_buffer(layout: &LayoutState, width: u32, height: u32) -> Vec<u8> {
+    let mut renderer = Renderer::new();
+    renderer.render(layout, [width, height]);
+    renderer.into_image_data()
 }
 
 /// The software renderer allows rendering layouts entirely on the CPU. This is
@@ -336,6 +385,36 @@ impl Renderer {
         )
     }
 
+    /// Renders the layout state just like [`render`](Self::render), but
+    /// additionally pushes the bounds of each component, as `(component_index,
+    /// [x, y, width, height])` in pixels, into the sink provided. These are
+    /// the exact positions the components got rendered at, which is useful for
+    /// building interactive overlays on top of the image.
+    pub fn render_with_bounds(
+        &mut self,
+        state: &LayoutState,
+        dimensions: [u32; 2],
+        bounds: &mut Vec<(usize, [f32; 4])>,
+    ) -> Option<(f32, f32)> {
+        let new_resolution = self.render(state, dimensions);
+        bounds.extend(
+            self.renderer
+                .scene_manager
+                .component_bounds()
+                .iter()
+                .copied()
+                .enumerate(),
+        );
+        new_resolution
+    }
+
+    /// Returns whether the last call to [`render`](Self::render) produced a
+    /// different frame than the call before it. If it didn't, the image is
+    /// still the same, so hosts can skip presenting it again.
+    pub const fn frame_changed(&self) -> bool {
+        self.renderer.frame_changed()
+    }
+
     /// Accesses the image as a byte slice of RGBA8 encoded pixels (red, green,
     /// blue, alpha with each channel being an u8).
     pub fn image_data(&self) -> &[u8] {
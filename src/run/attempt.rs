@@ -1,4 +1,6 @@
-use crate::{AtomicDateTime, Time, TimeSpan};
+use super::PauseInterval;
+use crate::{platform::prelude::*, AtomicDateTime, Time, TimeSpan};
+use alloc::collections::BTreeMap;
 
 /// An `Attempt` describes information about an attempt to run a specific category
 /// by a specific runner in the past. Every time a new attempt is started and
@@ -10,6 +12,11 @@ pub struct Attempt {
     started: Option<AtomicDateTime>,
     ended: Option<AtomicDateTime>,
     pause_time: Option<TimeSpan>,
+    metadata: Option<BTreeMap<String, String>>,
+    pause_intervals: Vec<PauseInterval>,
+    loading_times: Option<TimeSpan>,
+    invalid_reason: Option<String>,
+    comparison: Option<String>,
 }
 
 impl Attempt {
@@ -35,6 +42,11 @@ impl Attempt {
             started,
             ended,
             pause_time,
+            metadata: None,
+            pause_intervals: Vec::new(),
+            loading_times: None,
+            invalid_reason: None,
+            comparison: None,
         }
     }
 
@@ -87,4 +99,93 @@ impl Attempt {
     pub const fn ended(&self) -> Option<AtomicDateTime> {
         self.ended
     }
+
+    /// Accesses the metadata that got attached to the attempt when it was
+    /// started. This may for example be the seed of a randomizer. `None` is
+    /// returned if there is no metadata attached to the attempt.
+    #[inline]
+    pub const fn metadata(&self) -> Option<&BTreeMap<String, String>> {
+        self.metadata.as_ref()
+    }
+
+    /// Grants mutable access to the metadata of the attempt. If there is no
+    /// metadata attached to the attempt yet, it gets attached.
+    #[inline]
+    pub fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        self.metadata.get_or_insert_with(BTreeMap::new)
+    }
+
+    /// Sets the metadata of the attempt. Empty metadata is not attached to the
+    /// attempt at all.
+    #[inline]
+    pub fn set_metadata(&mut self, metadata: BTreeMap<String, String>) {
+        self.metadata = (!metadata.is_empty()).then_some(metadata);
+    }
+
+    /// Accesses the individual pauses of the attempt along with their labels.
+    /// This may be empty if the attempt wasn't paused or the information
+    /// wasn't stored.
+    #[inline]
+    pub fn pause_intervals(&self) -> &[PauseInterval] {
+        &self.pause_intervals
+    }
+
+    /// Grants mutable access to the individual pauses of the attempt.
+    #[inline]
+    pub fn pause_intervals_mut(&mut self) -> &mut Vec<PauseInterval> {
+        &mut self.pause_intervals
+    }
+
+    /// Accesses the total amount of time the game has been loading during the
+    /// attempt, which is the difference between its Real Time and its Game
+    /// Time. This returns `None` if Game Time wasn't initialized during the
+    /// attempt or the information wasn't stored.
+    #[inline]
+    pub const fn loading_times(&self) -> Option<TimeSpan> {
+        self.loading_times
+    }
+
+    /// Sets the total amount of time the game has been loading during the
+    /// attempt.
+    #[inline]
+    pub fn set_loading_times(&mut self, loading_times: Option<TimeSpan>) {
+        self.loading_times = loading_times;
+    }
+
+    /// Returns whether the attempt got marked as invalid, such as when it was
+    /// run in the wrong category. Invalid attempts stay in the Attempt History,
+    /// but can be excluded from the statistics via
+    /// [`Run::without_invalid_attempts`](super::Run::without_invalid_attempts).
+    #[inline]
+    pub const fn is_invalid(&self) -> bool {
+        self.invalid_reason.is_some()
+    }
+
+    /// Accesses the reason the attempt got marked as invalid for. This returns
+    /// `None` if the attempt is valid.
+    #[inline]
+    pub fn invalid_reason(&self) -> Option<&str> {
+        self.invalid_reason.as_deref()
+    }
+
+    /// Marks the attempt as invalid for the reason provided. Passing `None`
+    /// marks the attempt as valid again.
+    #[inline]
+    pub fn set_invalid_reason(&mut self, reason: Option<String>) {
+        self.invalid_reason = reason;
+    }
+
+    /// Accesses the name of the comparison that was selected when the attempt
+    /// ended. This returns `None` if this information is not known.
+    #[inline]
+    pub fn comparison(&self) -> Option<&str> {
+        self.comparison.as_deref()
+    }
+
+    /// Sets the name of the comparison that was selected when the attempt
+    /// ended.
+    #[inline]
+    pub fn set_comparison(&mut self, comparison: Option<String>) {
+        self.comparison = comparison;
+    }
 }
@@ -18,6 +18,7 @@ mod attempt;
 mod comparisons;
 pub mod editor;
 pub mod parser;
+mod pause_interval;
 mod run_metadata;
 pub mod saver;
 mod segment;
@@ -29,6 +30,7 @@ mod tests;
 pub use attempt::Attempt;
 pub use comparisons::Comparisons;
 pub use editor::{Editor, RenameError};
+pub use pause_interval::PauseInterval;
 pub use run_metadata::{CustomVariable, RunMetadata};
 pub use segment::Segment;
 pub use segment_history::SegmentHistory;
@@ -253,6 +255,13 @@ impl Run {
         &self.attempt_history
     }
 
+    /// Grants mutable access to the history of all the runs that have been
+    /// attempted.
+    #[inline]
+    pub fn attempt_history_mut(&mut self) -> &mut Vec<Attempt> {
+        &mut self.attempt_history
+    }
+
     /// Accesses the custom comparisons that are stored in this Run. This
     /// includes `Personal Best` but excludes all the other Comparison
     /// Generators.
@@ -493,6 +502,47 @@ impl Run {
         self.reattach_unattached_segment_history_elements();
     }
 
+    /// Creates a copy of the Run that excludes the attempts marked as invalid
+    /// from the Segment Histories. The Best Segment times are recalculated from
+    /// the remaining history, so any analysis performed on the copy, such as
+    /// the Sum of Best Segments, isn't influenced by the invalid attempts. The
+    /// invalid attempts themselves are kept in the Attempt History and the
+    /// comparisons are not modified.
+    pub fn without_invalid_attempts(&self) -> Run {
+        let mut run = self.clone();
+        let invalid = run
+            .attempt_history
+            .iter()
+            .filter(|attempt| attempt.is_invalid())
+            .map(Attempt::index)
+            .collect::<HashSet<_>>();
+
+        if invalid.is_empty() {
+            return run;
+        }
+
+        for segment in &mut run.segments {
+            segment
+                .segment_history_mut()
+                .retain(|&(index, _)| !invalid.contains(&index));
+
+            let mut best_segment_time = Time::default();
+            for (_, time) in segment.segment_history() {
+                for method in TimingMethod::all() {
+                    if let Some(time) = time[method] {
+                        if best_segment_time[method].map_or(true, |best| time < best) {
+                            best_segment_time[method] = Some(time);
+                        }
+                    }
+                }
+            }
+            segment.set_best_segment_time(best_segment_time);
+        }
+
+        run.fix_splits();
+        run
+    }
+
     /// Clears out the Attempt History and the Segment Histories of all the segments.
     pub fn clear_history(&mut self) {
         self.attempt_history.clear();
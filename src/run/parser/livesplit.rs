@@ -3,6 +3,7 @@
 use super::super::ComparisonError;
 use crate::{
     platform::prelude::*,
+    run::PauseInterval,
     util::xml::{
         helper::{
             attribute, attribute_escaped_err, end_tag, optional_attribute_escaped_err,
@@ -13,7 +14,7 @@ use crate::{
     },
     AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan,
 };
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, collections::BTreeMap};
 use core::{mem::MaybeUninit, str};
 use time::{Date, PrimitiveDateTime};
 
@@ -381,6 +382,11 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
         parse_children(reader, |reader, _, attributes| {
             let mut time = Time::new();
             let mut pause_time = None;
+            let mut loading_times = None;
+            let mut invalid_reason = None;
+            let mut comparison = None;
+            let mut metadata = BTreeMap::new();
+            let mut pause_intervals = Vec::new();
             let mut index = None;
             let (mut started, mut started_synced) = (None, false);
             let (mut ended, mut ended_synced) = (None, false);
@@ -405,6 +411,45 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
                 "RealTime" => time_span_opt(reader, |t| time.real_time = t),
                 "GameTime" => time_span_opt(reader, |t| time.game_time = t),
                 "PauseTime" => time_span_opt(reader, |t| pause_time = t),
+                "LoadingTimes" => time_span_opt(reader, |t| loading_times = t),
+                "InvalidReason" => text(reader, |t| invalid_reason = Some(t.into_owned())),
+                "Comparison" => text(reader, |t| comparison = Some(t.into_owned())),
+                "Metadata" => parse_children(reader, |reader, _, attributes| {
+                    let mut name = String::new();
+                    let mut value = String::new();
+                    type_hint(attribute(attributes, "name", |t| name = t.into_owned()))?;
+                    type_hint(text(reader, |t| value = t.into_owned()))?;
+                    metadata.insert(name, value);
+                    Ok(())
+                }),
+                "PauseIntervals" => parse_children(reader, |reader, _, attributes| {
+                    let mut label = String::new();
+                    let (mut started, mut started_synced) = (None, false);
+                    let (mut ended, mut ended_synced) = (None, false);
+
+                    type_hint(parse_attributes(attributes, |k, v| {
+                        match k {
+                            "label" => label = v.unescape_str(),
+                            "started" => started = Some(parse_date_time(v.escaped())?),
+                            "isStartedSynced" => started_synced = parse_bool(v.escaped())?,
+                            "ended" => ended = Some(parse_date_time(v.escaped())?),
+                            "isEndedSynced" => ended_synced = parse_bool(v.escaped())?,
+                            _ => {}
+                        }
+                        Ok(true)
+                    }))?;
+
+                    let started = started.ok_or(Error::Xml {
+                        source: XmlError::AttributeNotFound,
+                    })?;
+                    pause_intervals.push(PauseInterval::new(
+                        label,
+                        AtomicDateTime::new(started, started_synced),
+                        ended.map(|t| AtomicDateTime::new(t, ended_synced)),
+                    ));
+
+                    end_tag(reader)
+                }),
                 _ => end_tag(reader),
             })?;
 
@@ -418,6 +463,13 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
             };
 
             run.add_attempt_with_index(time, index, started, ended, pause_time);
+            if let Some(attempt) = run.attempt_history_mut().last_mut() {
+                attempt.set_metadata(metadata);
+                *attempt.pause_intervals_mut() = pause_intervals;
+                attempt.set_loading_times(loading_times);
+                attempt.set_invalid_reason(invalid_reason);
+                attempt.set_comparison(comparison);
+            }
 
             Ok(())
         })
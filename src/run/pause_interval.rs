@@ -0,0 +1,59 @@
+use crate::{platform::prelude::*, AtomicDateTime, TimeSpan};
+
+/// A `PauseInterval` describes a single time an attempt got paused. Each pause
+/// can be labeled with the reason for it, such as a loading screen or a
+/// cutscene, which allows for breaking down where the time of an attempt went.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PauseInterval {
+    label: String,
+    started: AtomicDateTime,
+    ended: Option<AtomicDateTime>,
+}
+
+impl PauseInterval {
+    /// Creates a new `PauseInterval` with the label provided. The point in time
+    /// the pause ended is `None` if the attempt is still paused.
+    pub fn new<S: Into<String>>(
+        label: S,
+        started: AtomicDateTime,
+        ended: Option<AtomicDateTime>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            started,
+            ended,
+        }
+    }
+
+    /// Accesses the label describing the reason for the pause. Pauses without
+    /// a specific reason have an empty label.
+    #[inline]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Accesses the point in time the pause started at.
+    #[inline]
+    pub const fn started(&self) -> AtomicDateTime {
+        self.started
+    }
+
+    /// Accesses the point in time the pause ended at. This returns `None` if
+    /// the attempt is still paused.
+    #[inline]
+    pub const fn ended(&self) -> Option<AtomicDateTime> {
+        self.ended
+    }
+
+    /// Sets the point in time the pause ended at.
+    #[inline]
+    pub fn set_ended(&mut self, ended: Option<AtomicDateTime>) {
+        self.ended = ended;
+    }
+
+    /// Returns how long the pause lasted. If the pause didn't end yet, `None`
+    /// is returned.
+    pub fn duration(&self) -> Option<TimeSpan> {
+        Some(self.ended? - self.started)
+    }
+}
@@ -205,6 +205,12 @@ impl RunMetadata {
         self.custom_variables.shift_remove(name);
     }
 
+    /// Removes all the temporary custom variables. Permanent custom variables
+    /// are kept.
+    pub fn remove_temporary_custom_variables(&mut self) {
+        self.custom_variables.retain(|_, var| var.is_permanent);
+    }
+
     /// Returns an iterator iterating over all the custom variables and their
     /// values. This includes both temporary and permanent variables.
     pub fn custom_variables(&self) -> Iter<'_, CustomVariable> {
@@ -237,7 +237,12 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
 
                     let is_empty = attempt.time().real_time.is_none()
                         && attempt.time().game_time.is_none()
-                        && attempt.pause_time().is_none();
+                        && attempt.pause_time().is_none()
+                        && attempt.metadata().is_none()
+                        && attempt.pause_intervals().is_empty()
+                        && attempt.loading_times().is_none()
+                        && !attempt.is_invalid()
+                        && attempt.comparison().is_none();
 
                     if !is_empty {
                         tag.content(|writer| {
@@ -251,6 +256,72 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                                 )?;
                             }
 
+                            if let Some(loading_times) = attempt.loading_times() {
+                                writer.tag_with_text_content(
+                                    "LoadingTimes",
+                                    NO_ATTRIBUTES,
+                                    DisplayValue(Complete.format(loading_times)),
+                                )?;
+                            }
+
+                            if let Some(reason) = attempt.invalid_reason() {
+                                writer.tag_with_text_content(
+                                    "InvalidReason",
+                                    NO_ATTRIBUTES,
+                                    reason,
+                                )?;
+                            }
+
+                            if let Some(comparison) = attempt.comparison() {
+                                writer.tag_with_text_content(
+                                    "Comparison",
+                                    NO_ATTRIBUTES,
+                                    comparison,
+                                )?;
+                            }
+
+                            if let Some(metadata) = attempt.metadata() {
+                                scoped_iter(
+                                    writer,
+                                    "Metadata",
+                                    metadata,
+                                    |writer, (name, value)| {
+                                        writer.tag_with_text_content(
+                                            "Variable",
+                                            [("name", name.as_str())],
+                                            value.as_str(),
+                                        )
+                                    },
+                                )?;
+                            }
+
+                            if !attempt.pause_intervals().is_empty() {
+                                scoped_iter(
+                                    writer,
+                                    "PauseIntervals",
+                                    attempt.pause_intervals(),
+                                    |writer, interval| {
+                                        writer.tag("Pause", |mut tag| {
+                                            tag.attribute("label", interval.label())?;
+                                            let started = interval.started();
+                                            date(&mut tag, "started", started.time)?;
+                                            tag.attribute(
+                                                "isStartedSynced",
+                                                bool(started.synced_with_atomic_clock),
+                                            )?;
+                                            if let Some(ended) = interval.ended() {
+                                                date(&mut tag, "ended", ended.time)?;
+                                                tag.attribute(
+                                                    "isEndedSynced",
+                                                    bool(ended.synced_with_atomic_clock),
+                                                )?;
+                                            }
+                                            Ok(())
+                                        })
+                                    },
+                                )?;
+                            }
+
                             Ok(())
                         })?;
                     }
@@ -29,6 +29,7 @@ pub struct Segment {
     segment_history: SegmentHistory,
     comparisons: Comparisons,
     variables: HashMap<String, String>,
+    countdown_splittable: bool,
 }
 
 impl Segment {
@@ -196,6 +197,22 @@ impl Segment {
         self.variables.clear();
     }
 
+    /// Returns whether the segment can be split while the Timer is still
+    /// counting down a negative start offset.
+    #[inline]
+    pub const fn is_countdown_splittable(&self) -> bool {
+        self.countdown_splittable
+    }
+
+    /// Sets whether the segment can be split while the Timer is still counting
+    /// down a negative start offset. Usually a split is ignored until the
+    /// offset has passed, which is still the case for all the segments that
+    /// aren't marked.
+    #[inline]
+    pub fn set_countdown_splittable(&mut self, countdown_splittable: bool) {
+        self.countdown_splittable = countdown_splittable;
+    }
+
     /// Clears all the information the segment stores when it has been splitted,
     /// such as the split's time and variables.
     pub fn clear_split_info(&mut self) {
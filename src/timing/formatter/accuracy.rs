@@ -1,6 +1,6 @@
 use super::{format_padded, NANOS_PER_HUNDREDTH, NANOS_PER_MILLI, NANOS_PER_TENTH};
 use core::{
-    fmt::{Display, Formatter, Result},
+    fmt::{Display, Formatter, Result, Write},
     str,
 };
 use serde::{Deserialize, Serialize};
@@ -22,9 +22,20 @@ pub enum Accuracy {
 impl Accuracy {
     /// Formats the nanoseconds provided with the chosen accuracy.
     pub const fn format_nanoseconds(self, nanoseconds: u32) -> FractionalPart {
+        self.format_nanoseconds_with_separator(nanoseconds, '.')
+    }
+
+    /// Formats the nanoseconds provided with the chosen accuracy, using the
+    /// separator provided in front of the digits.
+    pub const fn format_nanoseconds_with_separator(
+        self,
+        nanoseconds: u32,
+        separator: char,
+    ) -> FractionalPart {
         FractionalPart {
             accuracy: self,
             nanoseconds,
+            separator,
         }
     }
 }
@@ -33,6 +44,7 @@ impl Accuracy {
 pub struct FractionalPart {
     accuracy: Accuracy,
     nanoseconds: u32,
+    separator: char,
 }
 
 impl Display for FractionalPart {
@@ -40,7 +52,7 @@ impl Display for FractionalPart {
         match self.accuracy {
             Accuracy::Seconds => Ok(()),
             Accuracy::Tenths => {
-                f.write_str(".")?;
+                f.write_char(self.separator)?;
                 let v = (self.nanoseconds / NANOS_PER_TENTH) as u8;
                 assert!(v < 10);
                 // SAFETY: We ensured the value is between 0 and 10, so adding
@@ -49,13 +61,13 @@ impl Display for FractionalPart {
                 unsafe { f.write_str(str::from_utf8_unchecked(&[v + b'0'])) }
             }
             Accuracy::Hundredths => {
-                f.write_str(".")?;
+                f.write_char(self.separator)?;
                 f.write_str(format_padded(
                     (self.nanoseconds / NANOS_PER_HUNDREDTH) as u8,
                 ))
             }
             Accuracy::Milliseconds => {
-                f.write_str(".")?;
+                f.write_char(self.separator)?;
                 let first = (self.nanoseconds / NANOS_PER_TENTH) as u8;
                 let second_and_third =
                     ((self.nanoseconds % NANOS_PER_TENTH) / NANOS_PER_MILLI) as u8;
@@ -127,4 +139,20 @@ mod tests {
         assert_eq!(acc.format_nanoseconds(109_654_321).to_string(), ".109");
         assert_eq!(acc.format_nanoseconds(999_999_999).to_string(), ".999");
     }
+
+    #[test]
+    fn format_with_separator() {
+        let acc = Accuracy::Hundredths;
+        assert_eq!(
+            acc.format_nanoseconds_with_separator(789_654_321, ',')
+                .to_string(),
+            ",78"
+        );
+        assert_eq!(
+            Accuracy::Seconds
+                .format_nanoseconds_with_separator(789_654_321, ',')
+                .to_string(),
+            ""
+        );
+    }
 }
@@ -0,0 +1,148 @@
+use super::{Accuracy, DigitsFormat, NANOS_PER_HUNDREDTH, NANOS_PER_MILLI, NANOS_PER_TENTH};
+use crate::{platform::Duration, TimeSpan};
+use serde::{Deserialize, Serialize};
+
+/// The Rounding Mode describes how a time is rounded to the accuracy it is
+/// visualized with. This only affects how the time is shown, the time itself
+/// is always stored with full precision.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// Cuts off all the digits that are not shown (12:34.569 → 12:34.56).
+    Truncate,
+    /// Rounds to the nearest value that can be shown (12:34.565 → 12:34.57).
+    Round,
+    /// Rounds up to the next value that can be shown (12:34.561 → 12:34.57).
+    Ceil,
+}
+
+/// The Format Options describe locale specific preferences for visualizing
+/// times, such as the decimal separator to use. Components that support them
+/// apply these on top of their own settings. For now this is only the
+/// [`Timer Component`](crate::component::timer), which uses them via the
+/// [`Timer Formatters`](super::timer).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatOptions {
+    /// The separator to show between the seconds and the fractional part.
+    pub decimal_separator: char,
+    /// Overrides how many digits of the fractional part are shown. If this is
+    /// `None`, the accuracy chosen by the component is used.
+    pub accuracy: Option<Accuracy>,
+    /// Specifies whether to always show the hours, even if the time is shorter
+    /// than an hour.
+    pub always_show_hours: bool,
+    /// Specifies how times are rounded to the accuracy they are shown with.
+    /// Negative times are rounded based on their magnitude.
+    pub rounding_mode: RoundingMode,
+}
+
+impl FormatOptions {
+    /// Creates new default Format Options, which don't change the way times
+    /// are visualized.
+    pub const fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            accuracy: None,
+            always_show_hours: false,
+            rounding_mode: RoundingMode::Truncate,
+        }
+    }
+
+    /// Resolves the accuracy to use based on the accuracy chosen by the
+    /// component.
+    pub fn resolve_accuracy(&self, accuracy: Accuracy) -> Accuracy {
+        self.accuracy.unwrap_or(accuracy)
+    }
+
+    /// Rounds the time provided based on the Rounding Mode, such that
+    /// visualizing it with the resolved accuracy shows the correctly rounded
+    /// value.
+    pub fn round(&self, time: TimeSpan, accuracy: Accuracy) -> TimeSpan {
+        let unit = match self.resolve_accuracy(accuracy) {
+            Accuracy::Seconds => 1_000_000_000,
+            Accuracy::Tenths => NANOS_PER_TENTH,
+            Accuracy::Hundredths => NANOS_PER_HUNDREDTH,
+            Accuracy::Milliseconds => NANOS_PER_MILLI,
+        };
+
+        let (seconds, nanoseconds) = time.to_seconds_and_subsec_nanoseconds();
+        let is_negative = (seconds | nanoseconds as i64) < 0;
+        let (seconds, nanoseconds) = (seconds.unsigned_abs(), nanoseconds.unsigned_abs());
+
+        let remainder = nanoseconds % unit;
+        let truncated = nanoseconds - remainder;
+        let nanoseconds = match self.rounding_mode {
+            RoundingMode::Round if remainder >= unit / 2 => truncated + unit,
+            RoundingMode::Ceil if remainder > 0 => truncated + unit,
+            _ => truncated,
+        };
+
+        // The nanoseconds may have overflowed into the next second, which the
+        // Duration takes care of.
+        let rounded = TimeSpan::from(Duration::new(seconds as i64, nanoseconds as i32));
+        if is_negative {
+            -rounded
+        } else {
+            rounded
+        }
+    }
+
+    /// Resolves the digits format to use based on the digits format chosen by
+    /// the component.
+    pub const fn resolve_digits_format(&self, digits_format: DigitsFormat) -> DigitsFormat {
+        match digits_format {
+            DigitsFormat::SingleDigitHours | DigitsFormat::DoubleDigitHours => digits_format,
+            _ if self.always_show_hours => DigitsFormat::SingleDigitHours,
+            _ => digits_format,
+        }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(mode: RoundingMode, time: &str, accuracy: Accuracy) -> TimeSpan {
+        let options = FormatOptions {
+            rounding_mode: mode,
+            ..Default::default()
+        };
+        options.round(time.parse().unwrap(), accuracy)
+    }
+
+    #[test]
+    fn rounding() {
+        let parse = |time: &str| time.parse::<TimeSpan>().unwrap();
+
+        assert_eq!(
+            round(RoundingMode::Truncate, "12:34.569", Accuracy::Hundredths),
+            parse("12:34.56")
+        );
+        assert_eq!(
+            round(RoundingMode::Round, "12:34.565", Accuracy::Hundredths),
+            parse("12:34.57")
+        );
+        assert_eq!(
+            round(RoundingMode::Round, "12:34.564", Accuracy::Hundredths),
+            parse("12:34.56")
+        );
+        assert_eq!(
+            round(RoundingMode::Ceil, "12:34.561", Accuracy::Hundredths),
+            parse("12:34.57")
+        );
+        assert_eq!(
+            round(RoundingMode::Ceil, "12:34.999", Accuracy::Seconds),
+            parse("12:35")
+        );
+        assert_eq!(
+            round(RoundingMode::Round, "-0:01.5", Accuracy::Seconds),
+            parse("-0:02")
+        );
+    }
+}
@@ -25,14 +25,21 @@ mod complete;
 mod days;
 mod delta;
 mod digits_format;
+mod format_options;
 pub mod none_wrapper;
 mod regular;
 mod segment_time;
 pub mod timer;
 
 pub use self::{
-    accuracy::Accuracy, complete::Complete, days::Days, delta::Delta, digits_format::DigitsFormat,
-    regular::Regular, segment_time::SegmentTime,
+    accuracy::Accuracy,
+    complete::Complete,
+    days::Days,
+    delta::Delta,
+    digits_format::DigitsFormat,
+    format_options::{FormatOptions, RoundingMode},
+    regular::Regular,
+    segment_time::SegmentTime,
 };
 
 use crate::TimeSpan;
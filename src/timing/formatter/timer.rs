@@ -3,8 +3,8 @@
 //! is the Time Formatter pair used by the Timer Component.
 
 use super::{
-    format_padded, format_unpadded, Accuracy, DigitsFormat, TimeFormatter, DASH, MINUS,
-    SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
+    format_padded, format_unpadded, Accuracy, DigitsFormat, FormatOptions, TimeFormatter, DASH,
+    MINUS, SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
 };
 use crate::TimeSpan;
 use core::fmt::{Display, Formatter, Result};
@@ -125,6 +125,7 @@ impl Display for TimeInner {
 pub struct FractionInner {
     time: Option<TimeSpan>,
     accuracy: Accuracy,
+    decimal_separator: char,
 }
 
 /// The Time Formatter that visualizes the fractional part of the Time Formatter
@@ -140,21 +141,33 @@ pub struct FractionInner {
 /// * Hundredths `.12`
 pub struct Fraction {
     accuracy: Accuracy,
+    decimal_separator: char,
 }
 
 impl Fraction {
     /// Creates a new default Time Formatter that uses hundredths for showing
     /// the fractional part.
     pub const fn new() -> Self {
-        Fraction {
-            accuracy: Accuracy::Hundredths,
-        }
+        Fraction::with_accuracy(Accuracy::Hundredths)
     }
 
     /// Creates a new Time Formatter that uses the accuracy provided for showing
     /// the fractional part.
     pub const fn with_accuracy(accuracy: Accuracy) -> Self {
-        Fraction { accuracy }
+        Fraction {
+            accuracy,
+            decimal_separator: '.',
+        }
+    }
+
+    /// Creates a new Time Formatter that uses the accuracy provided for showing
+    /// the fractional part, unless the Format Options override it. The decimal
+    /// separator is taken from the Format Options.
+    pub fn with_format_options(accuracy: Accuracy, options: &FormatOptions) -> Self {
+        Fraction {
+            accuracy: options.resolve_accuracy(accuracy),
+            decimal_separator: options.decimal_separator,
+        }
     }
 }
 
@@ -174,6 +187,7 @@ impl TimeFormatter<'_> for Fraction {
         FractionInner {
             time: time.into(),
             accuracy: self.accuracy,
+            decimal_separator: self.decimal_separator,
         }
     }
 }
@@ -182,7 +196,9 @@ impl Display for FractionInner {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         if let Some(time) = self.time {
             let nanoseconds = time.to_duration().subsec_nanoseconds().unsigned_abs();
-            self.accuracy.format_nanoseconds(nanoseconds).fmt(f)
+            self.accuracy
+                .format_nanoseconds_with_separator(nanoseconds, self.decimal_separator)
+                .fmt(f)
         } else {
             Ok(())
         }
@@ -193,4 +209,23 @@ impl Display for FractionInner {
 fn test() {
     let time = "4:20.999999".parse::<TimeSpan>().unwrap();
     assert_eq!(Fraction::new().format(Some(time)).to_string(), ".99");
+
+    let options = FormatOptions {
+        decimal_separator: ',',
+        accuracy: Some(Accuracy::Milliseconds),
+        always_show_hours: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Fraction::with_format_options(Accuracy::Hundredths, &options)
+            .format(Some(time))
+            .to_string(),
+        ",999"
+    );
+    assert_eq!(
+        Time::with_digits_format(options.resolve_digits_format(DigitsFormat::SingleDigitSeconds))
+            .format(Some(time))
+            .to_string(),
+        "0:04:20"
+    );
 }
@@ -16,6 +16,12 @@ pub use self::time_span::{ParseError, TimeSpan};
 pub use self::time_stamp::TimeStamp;
 #[cfg(feature = "std")]
 pub use self::timer::SharedTimer;
-pub use self::timer::{CreationError as TimerCreationError, Snapshot, Timer, TimerState};
+pub use self::timer::{
+    Action as TimerAction, AvailableActions, ComparisonError as TimerComparisonError,
+    CreationError as TimerCreationError, DeltaCategory, LoggedAction, Observation, ReplaceRunError,
+    ResetKind, RunSummary, SegmentSummary, SessionError as TimerSessionError, SetSplitTimesError,
+    Snapshot, SplitFilterFunc, StartFromSplitError, StateIssue, Timer, TimerBuilder, TimerState,
+    TimerStatus,
+};
 pub use self::timer_phase::TimerPhase;
 pub use self::timing_method::TimingMethod;
@@ -0,0 +1,20 @@
+/// Describes which of the actions that control an attempt would currently have
+/// an effect on the Timer. This can be used to disable the buttons of a user
+/// interface that wouldn't do anything.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AvailableActions {
+    /// Whether a new attempt can be started.
+    pub start: bool,
+    /// Whether the current split can be split.
+    pub split: bool,
+    /// Whether the current split can be skipped.
+    pub skip: bool,
+    /// Whether the previous split can be undone.
+    pub undo: bool,
+    /// Whether the current attempt can be reset.
+    pub reset: bool,
+    /// Whether the current attempt can be paused.
+    pub pause: bool,
+    /// Whether the current attempt can be resumed.
+    pub resume: bool,
+}
@@ -0,0 +1,93 @@
+use super::{CreationError, OnTimerChange, OnTimerChangeFunc, Timer};
+use crate::{comparison::personal_best, platform::prelude::*, Run, TimingMethod};
+
+/// A builder for configuring a [`Timer`] before it gets created. This allows
+/// setting up the Timer in a single expression. Use [`Timer::builder`] to
+/// create one.
+#[derive(Debug)]
+pub struct TimerBuilder {
+    run: Run,
+    timing_method: TimingMethod,
+    use_utc: bool,
+    comparison: String,
+    on_change: Option<OnTimerChange>,
+    validate: bool,
+}
+
+impl Timer {
+    /// Creates a builder for configuring a Timer for the Run provided before
+    /// creating it. Anything not configured is set up just like
+    /// [`Timer::new`] does.
+    pub fn builder(run: Run) -> TimerBuilder {
+        TimerBuilder {
+            run,
+            timing_method: TimingMethod::RealTime,
+            use_utc: true,
+            comparison: personal_best::NAME.into(),
+            on_change: None,
+            validate: false,
+        }
+    }
+}
+
+impl TimerBuilder {
+    /// Sets the Timing Method the Timer starts out with.
+    pub const fn timing_method(mut self, method: TimingMethod) -> Self {
+        self.timing_method = method;
+        self
+    }
+
+    /// Sets whether the Timer measures time based on the UTC clock instead of
+    /// the monotonic clock.
+    pub const fn use_utc(mut self, use_utc: bool) -> Self {
+        self.use_utc = use_utc;
+        self
+    }
+
+    /// Sets the comparison the Timer starts out comparing against. The
+    /// comparison needs to exist in the Run.
+    pub fn comparison(mut self, comparison: &str) -> Self {
+        self.comparison.clear();
+        self.comparison.push_str(comparison);
+        self
+    }
+
+    /// Sets the function that gets called whenever the state of the Timer
+    /// changes.
+    pub fn on_change(mut self, func: OnTimerChangeFunc) -> Self {
+        self.on_change = Some(OnTimerChange(func));
+        self
+    }
+
+    /// Sets whether the Run gets fully validated via [`Timer::validate_run`]
+    /// when the Timer is created. This is disabled by default, so just like
+    /// with [`Timer::new`] only Runs without any segments are rejected.
+    pub const fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Creates the Timer with the configuration provided. This fails for the
+    /// same reasons [`Timer::new`] fails, if the validation is enabled and the
+    /// Run fails it or if the comparison doesn't exist in the Run.
+    pub fn build(self) -> Result<Timer, CreationError> {
+        if self.validate {
+            Timer::validate_run(&self.run)?;
+        }
+        let mut timer = Timer::new(self.run)?;
+        if timer
+            .set_current_comparison(self.comparison.as_str())
+            .is_err()
+        {
+            return Err(CreationError::UnknownComparison {
+                name: self.comparison,
+            });
+        }
+        timer.current_timing_method = self.timing_method;
+        timer.use_utc = self.use_utc;
+        if let Some(on_change) = self.on_change {
+            timer.on_timer_change = on_change;
+        }
+        Ok(timer)
+    }
+}
@@ -0,0 +1,99 @@
+use super::Timer;
+use crate::{comparison::personal_best, run};
+use snafu::ResultExt;
+
+/// The Error type for custom comparisons that couldn't be renamed or removed.
+#[derive(Debug, snafu::Snafu)]
+pub enum ComparisonError {
+    /// The comparison is not a custom comparison of the Run. Comparison
+    /// Generators and the Personal Best can't be renamed or removed.
+    NotCustom,
+    /// The new name of the comparison is invalid.
+    InvalidName {
+        /// The underlying error.
+        source: run::ComparisonError,
+    },
+}
+
+impl Timer {
+    fn custom_comparison_index(&self, name: &str) -> Result<usize, ComparisonError> {
+        if name == personal_best::NAME {
+            return Err(ComparisonError::NotCustom);
+        }
+        self.run
+            .custom_comparisons()
+            .iter()
+            .position(|c| c == name)
+            .ok_or(ComparisonError::NotCustom)
+    }
+
+    /// Returns whether the comparison with the name provided is generated
+    /// rather than being a custom comparison. This is the case for the
+    /// comparisons of all the Comparison Generators, as well as the Personal
+    /// Best, which is maintained by the Timer itself. Such comparisons can't be
+    /// renamed or removed. For custom comparisons and comparisons that don't
+    /// exist, `false` is returned.
+    pub fn is_generator_comparison(&self, name: &str) -> bool {
+        name == personal_best::NAME
+            || self
+                .run
+                .comparison_generators()
+                .iter()
+                .any(|generator| generator.name() == name)
+    }
+
+    /// Renames the custom comparison `old` to `new`. If the comparison is the
+    /// current comparison, the current comparison is renamed as well.
+    /// Comparison Generators and the Personal Best can't be renamed, and the
+    /// new name can't start with `[Race]` or already exist.
+    pub fn rename_comparison(&mut self, old: &str, new: &str) -> Result<(), ComparisonError> {
+        let index = self.custom_comparison_index(old)?;
+        if old == new {
+            return Ok(());
+        }
+        self.run
+            .validate_comparison_name(new)
+            .context(InvalidNameSnafu)?;
+
+        let name = &mut self.run.custom_comparisons_mut()[index];
+        name.clear();
+        name.push_str(new);
+
+        for segment in self.run.segments_mut() {
+            if let Some(time) = segment.comparisons_mut().remove(old) {
+                *segment.comparison_mut(new) = time;
+            }
+        }
+
+        if self.current_comparison == old {
+            self.current_comparison.clear();
+            self.current_comparison.push_str(new);
+        }
+
+        self.run.fix_splits();
+        self.run.regenerate_comparisons();
+        self.run.mark_as_modified();
+        Ok(())
+    }
+
+    /// Removes the custom comparison with the name provided. If it is the
+    /// current comparison, the Timer switches to the Personal Best.
+    /// Comparison Generators and the Personal Best can't be removed.
+    pub fn remove_comparison(&mut self, name: &str) -> Result<(), ComparisonError> {
+        let index = self.custom_comparison_index(name)?;
+        self.run.custom_comparisons_mut().remove(index);
+
+        for segment in self.run.segments_mut() {
+            segment.comparisons_mut().remove(name);
+        }
+
+        if self.current_comparison == name {
+            self.current_comparison = personal_best::NAME.into();
+        }
+
+        self.run.fix_splits();
+        self.run.regenerate_comparisons();
+        self.run.mark_as_modified();
+        Ok(())
+    }
+}
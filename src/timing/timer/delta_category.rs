@@ -0,0 +1,138 @@
+use super::{Snapshot, Timer};
+use crate::{
+    analysis::{check_best_segment, live_segment_time},
+    settings::SemanticColor,
+    TimeSpan, TimerPhase, TimingMethod,
+};
+
+/// Describes how the runner is doing compared to the current comparison. This
+/// is the category LiveSplit uses to decide the color of a delta.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DeltaCategory {
+    /// There is no delta to categorize, such as when there's no attempt in
+    /// progress.
+    Neutral,
+    /// The runner is ahead of the comparison and is gaining even more time.
+    AheadGainingTime,
+    /// The runner is ahead of the comparison, but is losing time.
+    AheadLosingTime,
+    /// The runner is behind the comparison, but is gaining back time.
+    BehindGainingTime,
+    /// The runner is behind the comparison and is losing even more time.
+    BehindLosingTime,
+    /// The runner achieved a new best segment.
+    BestSegment,
+}
+
+impl From<DeltaCategory> for SemanticColor {
+    fn from(category: DeltaCategory) -> Self {
+        match category {
+            DeltaCategory::Neutral => SemanticColor::Default,
+            DeltaCategory::AheadGainingTime => SemanticColor::AheadGainingTime,
+            DeltaCategory::AheadLosingTime => SemanticColor::AheadLosingTime,
+            DeltaCategory::BehindGainingTime => SemanticColor::BehindGainingTime,
+            DeltaCategory::BehindLosingTime => SemanticColor::BehindLosingTime,
+            DeltaCategory::BestSegment => SemanticColor::BestSegment,
+        }
+    }
+}
+
+impl Timer {
+    /// Categorizes the delta of the current split to the current comparison
+    /// for the snapshot and timing method provided. The category describes
+    /// whether the runner is ahead or behind and whether they are gaining or
+    /// losing time compared to the previous split. The comparison times take
+    /// the transient comparison into account if one is installed.
+    ///
+    /// A segment in progress is only categorized as a
+    /// [`BestSegment`](DeltaCategory::BestSegment) if its projected segment
+    /// time is faster than the best segment time. The segment is projected to
+    /// take as long as the comparison's segment, unless it already took longer
+    /// than that. This way the live segment time being short at the beginning
+    /// of every segment doesn't count as a best segment. Once the attempt is
+    /// finished, the last segment is categorized as a best segment if it
+    /// actually is one.
+    ///
+    /// If there is no attempt in progress, the
+    /// [`Neutral`](DeltaCategory::Neutral) category is returned.
+    pub fn delta_category(&self, snapshot: &Snapshot<'_>, method: TimingMethod) -> DeltaCategory {
+        match snapshot.current_phase() {
+            TimerPhase::NotRunning => DeltaCategory::Neutral,
+            TimerPhase::Running | TimerPhase::Paused => {
+                let index = match snapshot.current_split_index() {
+                    Some(index) => index,
+                    None => return DeltaCategory::Neutral,
+                };
+
+                let is_best_segment = catch! {
+                    let comparison_segment =
+                        self.comparison_time(index)[method]? - self.previous_comparison_time(index, method)?;
+                    let live_segment = live_segment_time(snapshot, index, method)?;
+                    comparison_segment.max(live_segment)
+                        < self.run.segment(index).best_segment_time()[method]?
+                };
+                if is_best_segment.unwrap_or(false) {
+                    return DeltaCategory::BestSegment;
+                }
+
+                let delta = catch! {
+                    snapshot.current_time()[method]? - self.comparison_time(index)[method]?
+                };
+                self.categorize_delta(delta, index, method)
+            }
+            TimerPhase::Ended => {
+                let index = self.run.len() - 1;
+                if check_best_segment(self, index, method) {
+                    return DeltaCategory::BestSegment;
+                }
+                let delta = catch! {
+                    self.run.segment(index).split_time()[method]?
+                        - self.comparison_time(index)[method]?
+                };
+                self.categorize_delta(delta, index, method)
+            }
+        }
+    }
+
+    /// The comparison's split time of the segment before the one with the
+    /// index provided, which is zero for the first segment.
+    fn previous_comparison_time(&self, index: usize, method: TimingMethod) -> Option<TimeSpan> {
+        match index.checked_sub(1) {
+            Some(previous) => self.comparison_time(previous)[method],
+            None => Some(TimeSpan::zero()),
+        }
+    }
+
+    fn categorize_delta(
+        &self,
+        delta: Option<TimeSpan>,
+        index: usize,
+        method: TimingMethod,
+    ) -> DeltaCategory {
+        let delta = match delta {
+            Some(delta) => delta,
+            None => return DeltaCategory::Neutral,
+        };
+
+        // The delta of the most recent split that has a split time.
+        let last_delta = self.run.segments()[..index]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, segment)| {
+                catch! { segment.split_time()[method]? - self.comparison_time(i)[method]? }
+            });
+
+        if delta < TimeSpan::zero() {
+            if last_delta.map_or(false, |last| delta > last) {
+                DeltaCategory::AheadLosingTime
+            } else {
+                DeltaCategory::AheadGainingTime
+            }
+        } else if last_delta.map_or(false, |last| delta < last) {
+            DeltaCategory::BehindGainingTime
+        } else {
+            DeltaCategory::BehindLosingTime
+        }
+    }
+}
@@ -1,12 +1,49 @@
 use crate::{
-    comparison::personal_best, platform::prelude::*, util::PopulateString, AtomicDateTime, Run,
-    Segment, Time, TimeSpan, TimeStamp, TimerPhase, TimerPhase::*, TimingMethod,
+    analysis,
+    comparison::{best_possible, personal_best, BestPossible},
+    platform::{
+        math::f64::{round, sqrt},
+        prelude::*,
+    },
+    run::PauseInterval,
+    timing::formatter::FormatOptions,
+    util::PopulateString,
+    AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp, TimerPhase,
+    TimerPhase::*,
+    TimingMethod,
 };
+use alloc::collections::BTreeMap;
 use core::{mem, ops::Deref};
 
+mod available_actions;
+mod builder;
+mod comparisons;
+mod delta_category;
+mod observation;
+mod replay;
+mod reset_kind;
+mod run_summary;
+mod session;
+mod splits_state;
+mod state_validation;
+mod status;
 #[cfg(test)]
 mod tests;
 
+pub use self::{
+    available_actions::AvailableActions,
+    builder::TimerBuilder,
+    comparisons::ComparisonError,
+    delta_category::DeltaCategory,
+    observation::Observation,
+    replay::LoggedAction,
+    reset_kind::ResetKind,
+    run_summary::{RunSummary, SegmentSummary},
+    session::SessionError,
+    state_validation::StateIssue,
+    status::TimerStatus,
+};
+
 pub type OnTimerChangeFunc = fn(&TimerState);
 #[derive(Clone)]
 pub struct OnTimerChange(OnTimerChangeFunc);
@@ -20,6 +57,28 @@ impl std::fmt::Debug for OnTimerChange {
     }
 }
 
+/// A function deciding whether a split is allowed to be recorded, based on a
+/// snapshot of the Timer right before the split.
+///
+/// This is a plain function pointer rather than a boxed closure, just like the
+/// function passed to [`Timer::set_on_timer_change`]. The Timer is [`Clone`]
+/// and needs to stay [`Send`] and [`Sync`] so it can be used as a
+/// [`SharedTimer`], which a boxed closure with its own state could not
+/// guarantee. Any state the filter needs can be read from the snapshot
+/// instead, which provides access to the whole Timer, including the Run and
+/// its variables.
+pub type SplitFilterFunc = fn(&Snapshot<'_>) -> bool;
+#[derive(Clone)]
+struct SplitFilter(SplitFilterFunc);
+
+impl std::fmt::Debug for SplitFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.debug_struct("SplitFilter")
+            .field("function", &"user-defined-function")
+            .finish()
+    }
+}
+
 /// A `Timer` provides all the capabilities necessary for doing speedrun attempts.
 ///
 /// # Examples
@@ -58,6 +117,7 @@ pub struct Timer {
     phase: TimerPhase,
     current_split_index: Option<usize>,
     current_timing_method: TimingMethod,
+    display_method: Option<TimingMethod>,
     current_comparison: String,
     attempt_started: Option<AtomicDateTime>,
     attempt_ended: Option<AtomicDateTime>,
@@ -74,23 +134,84 @@ pub struct Timer {
     adjusted_start_time_utc: AtomicDateTime,
     use_utc: bool,
     on_timer_change: OnTimerChange,
+    format_options: FormatOptions,
+    transient_comparison: Option<Vec<Time>>,
+    attempt_metadata: BTreeMap<String, String>,
+    current_attempt_metadata: BTreeMap<String, String>,
+    idle_timeout: Option<TimeSpan>,
+    last_action: Action,
+    last_action_time: TimeStamp,
+    pause_intervals: Vec<PauseInterval>,
+    action_log: Option<Vec<(TimeSpan, LoggedAction)>>,
+    action_log_capacity: usize,
+    split_filter: Option<SplitFilter>,
+    split_debounce: Option<TimeSpan>,
+    last_split: Option<TimeStamp>,
+    input_latency: TimeSpan,
+    practice_mode: bool,
+    practice_attempt: bool,
+    game_time_smoothing: bool,
+    game_time_report: Option<GameTimeReport>,
+    min_segment_time: Option<TimeSpan>,
+    countdown_splits: bool,
+    final_time_override: Option<Time>,
+    is_following: bool,
+    state_precision: Option<u8>,
+    finish_detection: Option<TimeSpan>,
+    static_game_time: Option<(TimeSpan, TimeStamp)>,
+    replay_clock: Option<(TimeStamp, AtomicDateTime)>,
+    split_times_utc: Vec<Option<AtomicDateTime>>,
+    external_time: Option<TimeSpan>,
+}
+
+/// The last Game Time reported via [`Timer::set_game_time`] while smoothing,
+/// along with the Real Time it got reported at and the rate the Game Time
+/// progressed at since the report before it.
+#[derive(Debug, Copy, Clone)]
+struct GameTimeReport {
+    real_time: TimeSpan,
+    game_time: TimeSpan,
+    rate: Option<f64>,
 }
 
+/// The amount of actions the action log keeps by default before it starts
+/// dropping the oldest ones.
+const DEFAULT_ACTION_LOG_CAPACITY: usize = 1024;
+
+/// The amount of decimal places the times of a [`TimerState`] are rounded to
+/// by default.
+const DEFAULT_STATE_PRECISION: u8 = 3;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+/// An action that got performed on the Timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum Action {
+    /// No action was performed.
     #[default]
     None,
+    /// An attempt was started.
     Start,
+    /// A split was performed.
     Split,
+    /// A split was skipped.
     Skip,
+    /// A split was undone.
     Undo,
+    /// The attempt was reset.
     Reset,
+    /// The attempt was paused.
     Pause,
+    /// The attempt was resumed.
     Resume,
+    /// The attempt that is being reset beat the Personal Best and got stored
+    /// as the new Personal Best.
+    NewPersonalBest,
 }
-///
+/// A serializable snapshot of the state of an attempt, as created by
+/// [`Timer::timer_state`]. All the times are stored in seconds and are rounded
+/// to 3 decimal places by default. This can be changed via
+/// [`Timer::set_state_precision`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimerState {
     ///
@@ -102,6 +223,10 @@ pub struct TimerState {
     pub current_split_index: Option<usize>,
     ///
     pub current_timing_method: TimingMethod,
+    /// The Timing Method used for displaying times, if it differs from the
+    /// current Timing Method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_method: Option<TimingMethod>,
     ///
     pub current_comparison: String,
     ///
@@ -120,6 +245,10 @@ pub struct TimerState {
     ///
     #[serde(skip_serializing_if = "Option::is_none")]
     pub loading_times: Option<f64>,
+    /// Whether the Game Time is initialized, i.e. whether Game Time is
+    /// available for the current attempt at all.
+    #[serde(default)]
+    pub game_time_initialized: bool,
     //path: String,
     /// UTC TimeStamps
     start_time_utc: ADT,
@@ -134,6 +263,21 @@ pub struct TimerState {
     ///
     #[serde(default)]
     pub action: Action,
+    ///
+    #[serde(default)]
+    pause_intervals: Vec<PauseIntervalState>,
+    /// The points in time each of the splits happened at.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    split_times_utc: Vec<Option<ADT>>,
+    /// Whether the attempt got started on a later split via
+    /// [`Timer::start_from_split`] and thus is always discarded on reset.
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    pub practice_attempt: bool,
+    /// By how many seconds the previous Personal Best got beaten. This is only
+    /// provided along with the [`NewPersonalBest`](Action::NewPersonalBest)
+    /// action and only if there was a previous Personal Best.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub personal_best_improvement: Option<f64>,
 }
 
 impl From<TimeSpan> for f64 {
@@ -166,10 +310,50 @@ impl From<AtomicDateTime> for ADT {
     }
 }
 
-impl From<&ADT> for AtomicDateTime {
-    fn from(adt: &ADT) -> Self {
-        let dt = crate::DateTime::parse(&adt.time, &Iso8601::DEFAULT).unwrap();
-        Self::new(dt, adt.synced)
+impl TryFrom<&ADT> for AtomicDateTime {
+    type Error = time::error::Parse;
+
+    fn try_from(adt: &ADT) -> Result<Self, Self::Error> {
+        let dt = crate::DateTime::parse(&adt.time, &Iso8601::DEFAULT)?;
+        Ok(Self::new(dt, adt.synced))
+    }
+}
+
+fn restore_timestamp(field: &'static str, adt: &ADT) -> Result<AtomicDateTime, StateIssue> {
+    AtomicDateTime::try_from(adt).map_err(|_| StateIssue::InvalidTimestamp { field })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PauseIntervalState {
+    label: String,
+    started: ADT,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ended: Option<ADT>,
+}
+
+impl From<&PauseInterval> for PauseIntervalState {
+    fn from(interval: &PauseInterval) -> Self {
+        Self {
+            label: interval.label().to_owned(),
+            started: interval.started().into(),
+            ended: interval.ended().map(|x| x.into()),
+        }
+    }
+}
+
+impl TryFrom<&PauseIntervalState> for PauseInterval {
+    type Error = StateIssue;
+
+    fn try_from(state: &PauseIntervalState) -> Result<Self, Self::Error> {
+        Ok(PauseInterval::new(
+            state.label.as_str(),
+            restore_timestamp("pause_intervals", &state.started)?,
+            state
+                .ended
+                .as_ref()
+                .map(|x| restore_timestamp("pause_intervals", x))
+                .transpose()?,
+        ))
     }
 }
 
@@ -228,6 +412,7 @@ impl From<&Timer> for TimerState {
             phase: format!("{:?}", timer.phase),
             current_split_index: timer.current_split_index,
             current_timing_method: timer.current_timing_method,
+            display_method: timer.display_method,
             current_comparison: timer.current_comparison.clone(),
             attempt_started: timer.attempt_started.map(|x| x.into()),
             attempt_ended: timer.attempt_ended.map(|x| x.into()),
@@ -235,11 +420,20 @@ impl From<&Timer> for TimerState {
             is_game_time_paused: timer.is_game_time_paused,
             game_time_pause_time: timer.game_time_pause_time.map(ts_to_f64),
             loading_times: timer.loading_times.map(ts_to_f64),
+            game_time_initialized: timer.is_game_time_initialized(),
             start_time_utc: timer.start_time_utc.into(),
             start_time_with_offset_utc: timer.start_time_with_offset_utc.into(),
             adjusted_start_time_utc: timer.adjusted_start_time_utc.into(),
             split_name,
             action: Action::None,
+            pause_intervals: timer.pause_intervals.iter().map(Into::into).collect(),
+            split_times_utc: timer
+                .split_times_utc
+                .iter()
+                .map(|time| time.map(Into::into))
+                .collect(),
+            practice_attempt: timer.practice_attempt,
+            personal_best_improvement: None,
         }
     }
 }
@@ -257,6 +451,38 @@ impl TimerState {
     pub fn to_json(&self) -> String {
         serde_json::to_string(&self).unwrap()
     }
+
+    /// Writes the state as a single line of JSON, terminated by a newline.
+    /// Writing multiple states to the same writer produces newline-delimited
+    /// JSON (NDJSON), where each line is a complete state on its own. This
+    /// makes it easy to stream states to another process or over a socket.
+    pub fn write_ndjson<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        serde_json::to_writer(&mut writer, self)?;
+        writer.write_all(b"\n")
+    }
+
+    /// Rounds all the times stored in the state to the amount of decimal
+    /// places provided. The times are stored in seconds, so rounding to 3
+    /// decimal places keeps millisecond precision. This avoids serializing
+    /// floating point noise such as `123.45600000000001`.
+    pub fn round_times(&mut self, decimals: u8) {
+        let factor = (0..decimals).fold(1.0, |factor, _| factor * 10.0);
+        let times = self
+            .splits
+            .iter_mut()
+            .flat_map(|split| [&mut split.real_time, &mut split.game_time])
+            .chain([
+                &mut self.game_time_pause_time,
+                &mut self.loading_times,
+                &mut self.personal_best_improvement,
+            ])
+            .flatten()
+            .chain([&mut self.time_paused_at]);
+
+        for seconds in times {
+            *seconds = round(*seconds * factor) / factor;
+        }
+    }
 }
 
 /// A snapshot represents a specific point in time that the timer was observed
@@ -265,6 +491,8 @@ impl TimerState {
 pub struct Snapshot<'timer> {
     timer: &'timer Timer,
     time: Time,
+    phase: TimerPhase,
+    current_split_index: Option<usize>,
 }
 
 impl Snapshot<'_> {
@@ -273,6 +501,17 @@ impl Snapshot<'_> {
     pub const fn current_time(&self) -> Time {
         self.time
     }
+
+    /// Returns the Timer Phase the timer was in when the snapshot was taken.
+    pub const fn current_phase(&self) -> TimerPhase {
+        self.phase
+    }
+
+    /// Returns the index of the split the timer was on when the snapshot was
+    /// taken. This is `None` if there was no attempt in progress.
+    pub const fn current_split_index(&self) -> Option<usize> {
+        self.current_split_index
+    }
 }
 
 impl Deref for Snapshot<'_> {
@@ -283,21 +522,110 @@ impl Deref for Snapshot<'_> {
 }
 
 /// A `SharedTimer` is a wrapper around the [`Timer`](crate::timing::Timer) that can be shared across multiple threads with multiple owners.
+/// Every action, such as a reset, fully applies all of its changes while the
+/// write lock is held, so readers never observe a Timer where an action is
+/// only partially applied.
 #[cfg(feature = "std")]
 pub type SharedTimer = alloc::sync::Arc<std::sync::RwLock<Timer>>;
 
 /// The Error type for creating a new Timer from a Run.
 #[derive(Debug, snafu::Snafu)]
 pub enum CreationError {
-    /// The Timer couldn't be created, because the Run has no segments.
+    /// The Timer couldn't be created, because the Run has no segments. Add at
+    /// least one segment to the Run to fix this.
     EmptyRun,
+    /// The Timer couldn't be created, because the Run contains the same
+    /// comparison multiple times. This is only returned if the Run gets
+    /// validated via [`Timer::validate_run`]. Rename or remove the duplicate
+    /// custom comparison to fix this.
+    DuplicateComparison {
+        /// The name of the comparison that is contained multiple times.
+        name: String,
+    },
+    /// The Timer couldn't be created, because the Run's offset is positive and
+    /// not smaller than the final time of the Personal Best, so every attempt
+    /// would start at or past the end of the run. This is only returned if the
+    /// Run gets validated via [`Timer::validate_run`]. Lower the offset with
+    /// [`Run::set_offset`] to fix this.
+    InvalidOffset,
+    /// The Timer couldn't be created, because the comparison it is supposed to
+    /// start out comparing against doesn't exist in the Run. This is only
+    /// returned by [`TimerBuilder::build`], as [`Timer::new`] always starts
+    /// out comparing against the Personal Best. Pick one of the Run's
+    /// comparisons to fix this.
+    UnknownComparison {
+        /// The name of the comparison that doesn't exist.
+        name: String,
+    },
+}
+
+/// The Error type for replacing the Run of a Timer while keeping the attempt
+/// in progress. The Run that couldn't be used is handed back as part of the
+/// error.
+#[derive(Debug, snafu::Snafu)]
+pub enum ReplaceRunError {
+    /// There is no attempt in progress that could be kept. Use
+    /// [`Timer::replace_run`] instead.
+    NoAttemptInProgress {
+        /// The Run that was provided.
+        run: Box<Run>,
+    },
+    /// The Run doesn't have the same amount of segments as the Run in use, so
+    /// it can't take over the split times of the attempt.
+    SegmentCountMismatch {
+        /// The Run that was provided.
+        run: Box<Run>,
+    },
+}
+
+impl ReplaceRunError {
+    /// Returns the Run that couldn't be used by the Timer.
+    pub fn into_run(self) -> Run {
+        match self {
+            Self::NoAttemptInProgress { run } | Self::SegmentCountMismatch { run } => *run,
+        }
+    }
+}
+
+/// The Error type for starting an attempt on a later split via
+/// [`Timer::start_from_split`].
+#[derive(Debug, snafu::Snafu)]
+pub enum StartFromSplitError {
+    /// There already is an attempt in progress.
+    AttemptInProgress,
+    /// The index of the split is out of range.
+    OutOfRange,
+    /// The segment preceding the split has no Personal Best split time for
+    /// the current timing method to start the Timer at.
+    NoPersonalBestSplitTime,
+}
+
+/// The Error type for setting the split times of an attempt via
+/// [`Timer::set_split_times`].
+#[derive(Debug, PartialEq, Eq, snafu::Snafu)]
+pub enum SetSplitTimesError {
+    /// There is no attempt in progress or finished to set the split times of.
+    NoAttempt,
+    /// The amount of split times doesn't match the amount of segments.
+    TimeCountMismatch,
+    /// A split time was provided for a split that the running attempt hasn't
+    /// reached yet.
+    SplitNotReached {
+        /// The index of the split that hasn't been reached yet.
+        index: usize,
+    },
 }
 
 impl Timer {
     /// Creates a new Timer based on a Run object storing all the information
     /// about the splits. The Run object needs to have at least one segment, so
-    /// that the Timer can store the final time. If a Run object with no
-    /// segments is provided, the Timer creation fails.
+    /// that the Timer can store the final time. Otherwise the Timer creation
+    /// fails with [`CreationError::EmptyRun`].
+    ///
+    /// The rest of the Run is not validated, as some callers intentionally use
+    /// Runs that wouldn't pass, such as Runs with a large positive offset. Use
+    /// [`TimerBuilder::validate`] or [`Timer::validate_run`] to opt into the
+    /// full validation.
     #[inline]
     pub fn new(mut run: Run) -> Result<Self, CreationError> {
         if run.is_empty() {
@@ -314,6 +642,7 @@ impl Timer {
             phase: NotRunning,
             current_split_index: None,
             current_timing_method: TimingMethod::RealTime,
+            display_method: None,
             current_comparison: personal_best::NAME.into(),
             attempt_started: None,
             attempt_ended: None,
@@ -329,9 +658,69 @@ impl Timer {
             adjusted_start_time_utc: now_utc,
             use_utc: true,
             on_timer_change: OnTimerChange(Timer::on_timer_change_noop),
+            format_options: FormatOptions::new(),
+            transient_comparison: None,
+            attempt_metadata: BTreeMap::new(),
+            current_attempt_metadata: BTreeMap::new(),
+            idle_timeout: None,
+            last_action: Action::None,
+            last_action_time: now,
+            pause_intervals: Vec::new(),
+            action_log: None,
+            action_log_capacity: DEFAULT_ACTION_LOG_CAPACITY,
+            split_filter: None,
+            split_debounce: None,
+            last_split: None,
+            input_latency: TimeSpan::zero(),
+            practice_mode: false,
+            practice_attempt: false,
+            game_time_smoothing: false,
+            game_time_report: None,
+            min_segment_time: None,
+            countdown_splits: false,
+            final_time_override: None,
+            is_following: false,
+            state_precision: Some(DEFAULT_STATE_PRECISION),
+            finish_detection: None,
+            static_game_time: None,
+            replay_clock: None,
+            split_times_utc: Vec::new(),
+            external_time: None,
         })
     }
 
+    /// Validates that the Run is structurally usable for timing. Whether the
+    /// Run has no segments is always checked first. After that the Run is
+    /// checked for comparisons that it contains multiple times and for an
+    /// offset that starts every attempt at or past the end of the Personal
+    /// Best. The error describes the first problem found.
+    pub fn validate_run(run: &Run) -> Result<(), CreationError> {
+        if run.is_empty() {
+            return Err(CreationError::EmptyRun);
+        }
+
+        for (index, name) in run.comparisons().enumerate() {
+            if run.comparisons().take(index).any(|c| c == name) {
+                return Err(CreationError::DuplicateComparison { name: name.into() });
+            }
+        }
+
+        let offset = run.offset();
+        if offset > TimeSpan::zero() {
+            let final_time = run.segments().last().unwrap().personal_best_split_time();
+            if TimingMethod::all()
+                .into_iter()
+                .filter_map(|method| final_time[method])
+                .max()
+                .map_or(false, |time| offset >= time)
+            {
+                return Err(CreationError::InvalidOffset);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Consumes the Timer and creates a Shared Timer that can be shared across
     /// multiple threads with multiple owners.
     #[cfg(feature = "std")]
@@ -344,6 +733,51 @@ impl Timer {
         self.use_utc = use_utc;
     }
 
+    /// Chooses whether the Timer is driven by the UTC clock, just like
+    /// [`Timer::use_utc`], but returns the Timer so further calls can be
+    /// chained.
+    #[inline]
+    pub fn with_utc(&mut self, use_utc: bool) -> &mut Self {
+        self.use_utc(use_utc);
+        self
+    }
+
+    /// Returns the Real Time provided by an external time source, if one is
+    /// driving the Timer.
+    #[inline]
+    pub const fn external_time(&self) -> Option<TimeSpan> {
+        self.external_time
+    }
+
+    /// Drives the Real Time of a running attempt by an external time source,
+    /// such as the timestamp of the current frame of a recording that is being
+    /// retimed. While it is set, it takes precedence over all the clocks, so
+    /// the Real Time of the running attempt is exactly the time provided.
+    ///
+    /// The external time is not adjusted for pauses. Pausing the attempt
+    /// freezes the Real Time at the external time at that point, and any
+    /// external time provided while paused is ignored until the attempt is
+    /// resumed, after which the external time is used as is again.
+    ///
+    /// Setting it to `None` resumes the regular clock based timing,
+    /// continuing from the Real Time the attempt had at that point. For a
+    /// running attempt that is the last external time provided, while a paused
+    /// attempt continues from the time it got paused at once it is resumed.
+    pub fn set_external_time(&mut self, real_time: Option<TimeSpan>) {
+        if let (Some(_), None) = (self.external_time, real_time) {
+            let anchor = match self.phase {
+                Running => self.external_time,
+                Paused => Some(self.time_paused_at),
+                _ => None,
+            };
+            if let Some(anchor) = anchor {
+                self.adjusted_start_time = self.now() - anchor;
+                self.adjusted_start_time_utc = self.now_utc() - anchor;
+            }
+        }
+        self.external_time = real_time;
+    }
+
     /// Takes out the Run from the Timer and resets the current attempt if there
     /// is one in progress. If the splits are to be updated, all the information
     /// of the current attempt is stored in the Run's history. Otherwise the
@@ -353,12 +787,27 @@ impl Timer {
         self.run
     }
 
+    /// Takes out the Run from the Timer without resetting the current attempt.
+    /// The Run still contains the split times of the attempt in progress. If
+    /// there is an attempt in progress, its state is returned as well, so that
+    /// it can be restored later on via [`Timer::replace_state`].
+    pub fn into_run_preserving(self) -> (Run, Option<TimerState>) {
+        let state = if self.phase != NotRunning {
+            Some(self.timer_state(Action::None))
+        } else {
+            None
+        };
+        (self.run, state)
+    }
+
     /// Replaces the Run object used by the Timer with the Run object provided.
     /// If the Run provided contains no segments, it can't be used for timing
     /// and is returned as the `Err` case of the `Result`. Otherwise the Run
     /// that was in use by the Timer is being returned. Before the Run is
     /// returned, the current attempt is reset and the splits are being updated
-    /// depending on the `update_splits` parameter.
+    /// depending on the `update_splits` parameter. Just like [`Timer::new`],
+    /// the rest of the Run is not validated, so use [`Timer::validate_run`]
+    /// beforehand if that is needed.
     pub fn replace_run(&mut self, mut run: Run, update_splits: bool) -> Result<Run, Run> {
         if run.is_empty() {
             return Err(run);
@@ -375,10 +824,46 @@ impl Timer {
         Ok(mem::replace(&mut self.run, run))
     }
 
+    /// Replaces the Run object used by the Timer with the Run object provided,
+    /// while keeping the current attempt in progress. The split times and
+    /// variables of the current attempt are transplanted into the new Run. If
+    /// there is no attempt in progress or the Run provided doesn't have the
+    /// same amount of segments as the Run in use, it can't take over the
+    /// attempt and is returned as part of the error. Otherwise the Run that was
+    /// in use by the Timer is being returned. The rest of the Run is not
+    /// validated, so use [`Timer::validate_run`] beforehand if that is needed.
+    pub fn replace_run_keeping_attempt(&mut self, mut run: Run) -> Result<Run, ReplaceRunError> {
+        if self.phase == NotRunning {
+            return Err(ReplaceRunError::NoAttemptInProgress { run: Box::new(run) });
+        }
+        if run.len() != self.run.len() {
+            return Err(ReplaceRunError::SegmentCountMismatch { run: Box::new(run) });
+        }
+
+        if !run.comparisons().any(|c| c == self.current_comparison) {
+            self.current_comparison = personal_best::NAME.to_string();
+        }
+
+        run.fix_splits();
+        run.regenerate_comparisons();
+
+        for (new, old) in run.segments_mut().iter_mut().zip(self.run.segments_mut()) {
+            new.set_split_time(old.split_time());
+            *new.variables_mut() = mem::take(old.variables_mut());
+        }
+        // The UTC split times are tracked by the Timer itself, so they stay
+        // aligned with the transplanted split times without any extra work.
+
+        let old_run = mem::replace(&mut self.run, run);
+        self.run.mark_as_modified();
+        Ok(old_run)
+    }
+
     /// Sets the Run object used by the Timer with the Run object provided. If
     /// the Run provided contains no segments, it can't be used for timing and
     /// is returned as the Err case of the Result. The Run object in use by the
-    /// Timer is dropped by this method.
+    /// Timer is dropped by this method. The rest of the Run is not validated,
+    /// so use [`Timer::validate_run`] beforehand if that is needed.
     pub fn set_run(&mut self, run: Run) -> Result<(), Run> {
         self.replace_run(run, false).map(drop)
     }
@@ -389,6 +874,122 @@ impl Timer {
         &self.run
     }
 
+    /// Iterates over all the segment times stored in the segment history of
+    /// the segment with the given index, along with the index of the attempt
+    /// they belong to. Only indices larger than 0 belong to actual attempts. If
+    /// the segment index is out of range, the iterator is empty.
+    pub fn segment_history(&self, segment_index: usize) -> impl Iterator<Item = (i32, Time)> + '_ {
+        self.run
+            .segments()
+            .get(segment_index)
+            .into_iter()
+            .flat_map(|segment| segment.segment_history().iter().copied())
+    }
+
+    /// The segment times of actual attempts stored in the segment history of
+    /// the segment with the given index. Entries with an index of 0 or lower
+    /// are best segments imported from other timers, so they are excluded.
+    /// Skipped segments are stored as null times and are excluded as well.
+    fn recorded_segment_times(
+        &self,
+        segment_index: usize,
+        method: TimingMethod,
+    ) -> impl Iterator<Item = TimeSpan> + '_ {
+        self.segment_history(segment_index)
+            .filter(|&(index, _)| index > 0)
+            .filter_map(move |(_, time)| time[method])
+    }
+
+    /// Calculates the median of all the segment times stored in the segment
+    /// history of the segment with the given index for the timing method
+    /// provided. Skipped segments are stored as null times in the segment
+    /// history and are excluded from the calculation, as their duration is
+    /// part of a combined segment. Imported best segments are not recorded
+    /// segment times of actual attempts, so they are excluded as well. If
+    /// there are no recorded segment times, `None` is returned.
+    pub fn segment_median(&self, index: usize, method: TimingMethod) -> Option<TimeSpan> {
+        let mut times = self
+            .recorded_segment_times(index, method)
+            .collect::<Vec<_>>();
+        times.sort_unstable();
+
+        if times.is_empty() {
+            return None;
+        }
+
+        let middle = times.len() / 2;
+        if times.len() % 2 == 0 {
+            let (low, high) = (times[middle - 1], times[middle]);
+            Some(TimeSpan::from_seconds(
+                0.5 * (low.total_seconds() + high.total_seconds()),
+            ))
+        } else {
+            Some(times[middle])
+        }
+    }
+
+    /// Calculates the mean of all the segment times stored in the segment
+    /// history of the segment with the given index for the timing method
+    /// provided. Skipped segments are stored as null times in the segment
+    /// history and are excluded from the calculation, as their duration is
+    /// part of a combined segment. Imported best segments are not recorded
+    /// segment times of actual attempts, so they are excluded as well. If
+    /// there are no recorded segment times, `None` is returned.
+    pub fn segment_mean(&self, index: usize, method: TimingMethod) -> Option<TimeSpan> {
+        let (sum, count) = self
+            .recorded_segment_times(index, method)
+            .fold((TimeSpan::zero(), 0), |(sum, count), time| {
+                (sum + time, count + 1)
+            });
+
+        if count == 0 {
+            return None;
+        }
+
+        Some(TimeSpan::from_seconds(sum.total_seconds() / count as f64))
+    }
+
+    /// Calculates the sample standard deviation of all the segment times
+    /// stored in the segment history of the segment with the given index for
+    /// the timing method provided. This describes how consistent the segment
+    /// is, which helps with finding the segments that need practice. Skipped
+    /// segments are stored as null times in the segment history and are
+    /// excluded from the calculation, as their duration is part of a combined
+    /// segment. Imported best segments are not recorded segment times of
+    /// actual attempts, so they are excluded as well. If there are fewer than
+    /// two recorded segment times, `None` is returned.
+    pub fn segment_std_dev(&self, index: usize, method: TimingMethod) -> Option<TimeSpan> {
+        let times = self
+            .recorded_segment_times(index, method)
+            .map(|time| time.total_seconds())
+            .collect::<Vec<_>>();
+
+        if times.len() < 2 {
+            return None;
+        }
+
+        let count = times.len() as f64;
+        let mean = times.iter().sum::<f64>() / count;
+        let variance = times.iter().map(|t| (t - mean) * (t - mean)).sum::<f64>() / (count - 1.0);
+
+        Some(TimeSpan::from_seconds(sqrt(variance)))
+    }
+
+    /// Calculates the Sum of Best Segments for the timing method provided by
+    /// summing up the shortest segment time stored in each segment's history.
+    /// Unlike the best segment times, this can't get stale after route
+    /// changes, as it's always based on the recorded segment times. Skipped
+    /// segments and imported best segments are excluded, as they are not
+    /// segment times of actual attempts. If any segment has no recorded
+    /// segment time, `None` is returned.
+    pub fn sum_of_best_from_history(&self, method: TimingMethod) -> Option<TimeSpan> {
+        let mut sum = TimeSpan::zero();
+        for index in 0..self.run.len() {
+            sum += self.recorded_segment_times(index, method).min()?;
+        }
+        Some(sum)
+    }
+
     /// Marks the Run as unmodified, so that it is known that all the changes
     /// have been saved.
     #[inline]
@@ -402,20 +1003,35 @@ impl Timer {
         self.phase
     }
 
+    /// The clocks are queried through these, so that a replay can drive the
+    /// Timer with the recorded points in time instead.
+    fn now(&self) -> TimeStamp {
+        match self.replay_clock {
+            Some((now, _)) => now,
+            None => TimeStamp::now(),
+        }
+    }
+
+    fn now_utc(&self) -> AtomicDateTime {
+        match self.replay_clock {
+            Some((_, now_utc)) => now_utc,
+            None => AtomicDateTime::now(),
+        }
+    }
+
     fn current_time(&self) -> Time {
-        let t0 = TimeStamp::now();
-        let t0_utc = AtomicDateTime::now();
         let real_time = match self.phase {
+            Running => self.running_real_time(self.use_utc || self.is_following),
             NotRunning => Some(self.run.offset()),
-            Running => Some(t0 - self.adjusted_start_time),
             Paused => Some(self.time_paused_at),
-            Ended => self.run.segments().last().unwrap().split_time().real_time,
-        };
-        let real_time_utc = match self.phase {
-            NotRunning => Some(self.run.offset()),
-            Running => Some(t0_utc - self.adjusted_start_time_utc),
-            Paused => Some(self.time_paused_at),
-            Ended => self.run.segments().last().unwrap().split_time().real_time,
+            // The final split may lack a Real Time if only Game Time got
+            // recorded for it, so fall back to the last recorded split time.
+            Ended => self
+                .run
+                .segments()
+                .iter()
+                .rev()
+                .find_map(|segment| segment.split_time().real_time),
         };
 
         let game_time = match self.phase {
@@ -423,8 +1039,17 @@ impl Timer {
             Ended => self.run.segments().last().unwrap().split_time().game_time,
             _ => {
                 if self.is_game_time_paused() {
-                    self.game_time_pause_time
+                    self.smoothed_game_time(real_time)
+                        .or(self.game_time_pause_time)
                 } else if self.is_game_time_initialized() {
+                    // The Game Time is always based on the monotonic clock,
+                    // only the Real Time may follow the UTC clock.
+                    let real_time = match self.phase {
+                        Running if self.use_utc || self.is_following => {
+                            self.running_real_time(false)
+                        }
+                        _ => real_time,
+                    };
                     catch! { real_time? - self.loading_times() }
                 } else {
                     None
@@ -433,14 +1058,22 @@ impl Timer {
         };
 
         Time::new()
-            .with_real_time(if self.use_utc {
-                real_time_utc
-            } else {
-                real_time
-            })
+            .with_real_time(real_time)
             .with_game_time(game_time)
     }
 
+    /// The Real Time of a running attempt. Only the clock that is actually
+    /// needed is queried, as this is called very frequently.
+    fn running_real_time(&self, use_utc: bool) -> Option<TimeSpan> {
+        if self.external_time.is_some() {
+            self.external_time
+        } else if use_utc {
+            Some(self.now_utc() - self.adjusted_start_time_utc)
+        } else {
+            Some(self.now() - self.adjusted_start_time)
+        }
+    }
+
     /// Creates a new snapshot of the timer at the point in time of this call.
     /// It represents a frozen state of the timer such that calculations can
     /// work with an entirely consistent view of the timer without the current
@@ -449,6 +1082,326 @@ impl Timer {
         Snapshot {
             timer: self,
             time: self.current_time(),
+            phase: self.phase,
+            current_split_index: self.current_split_index,
+        }
+    }
+
+    /// Returns the current time of the Timer for the timing method provided.
+    /// This is the same time a [`Snapshot`] taken at this point would report
+    /// for the timing method. `None` is returned if there's no time for the
+    /// timing method, such as when Game Time isn't initialized.
+    pub fn current_time_for(&self, method: TimingMethod) -> Option<TimeSpan> {
+        self.current_time()[method]
+    }
+
+    /// Returns the difference between the Game Time and the Real Time of the
+    /// snapshot provided. In the common case this is the negated loading
+    /// times, but as it is calculated directly from the frozen times of the
+    /// snapshot, it reflects what is actually being displayed. If either the
+    /// Game Time or the Real Time is missing, `None` is returned.
+    pub fn game_time_offset(&self, snapshot: &Snapshot<'_>) -> Option<TimeSpan> {
+        let time = snapshot.current_time();
+        catch! { time.game_time? - time.real_time? }
+    }
+
+    /// Returns the amount of attempts in the Attempt History that were
+    /// finished. Attempts that were reset before reaching the end don't have a
+    /// final time and are not counted. Together with the Run's
+    /// [`attempt_count`](Run::attempt_count) this can be used for calculating
+    /// a completion rate.
+    pub fn finished_attempt_count(&self) -> usize {
+        self.run
+            .attempt_history()
+            .iter()
+            .filter(|attempt| {
+                let time = attempt.time();
+                time.real_time.is_some() || time.game_time.is_some()
+            })
+            .count()
+    }
+
+    /// Compares two attempts from the Attempt History, identified by their
+    /// indices, and returns for each segment how much slower attempt `a` was
+    /// than attempt `b` for the timing method provided. Negative values mean
+    /// that attempt `a` was faster. The segment times are read from the
+    /// Segment History. `None` is returned for segments where either of the
+    /// attempts lacks a segment time, as well as for segments directly
+    /// following such a segment, as their segment times span multiple
+    /// segments.
+    pub fn compare_attempts(&self, a: i32, b: i32, method: TimingMethod) -> Vec<Option<TimeSpan>> {
+        let mut previous_complete = true;
+
+        self.run
+            .segments()
+            .iter()
+            .map(|segment| {
+                let history = segment.segment_history();
+                let delta = catch! { history.get(a)?[method]? - history.get(b)?[method]? };
+                let result = delta.filter(|_| previous_complete);
+                previous_complete = delta.is_some();
+                result
+            })
+            .collect()
+    }
+
+    /// Returns the total amount of time the game has been loading during the
+    /// attempt with the index provided from the Attempt History. `None` is
+    /// returned if there's no such attempt or its loading times weren't
+    /// stored, such as when Game Time wasn't initialized during it.
+    pub fn attempt_loading_time(&self, index: i32) -> Option<TimeSpan> {
+        self.run
+            .attempt_history()
+            .iter()
+            .find(|attempt| attempt.index() == index)?
+            .loading_times()
+    }
+
+    /// Returns the name of the comparison that was selected when the attempt
+    /// with the index provided from the Attempt History ended. `None` is
+    /// returned if there's no such attempt or the comparison wasn't stored.
+    pub fn attempt_comparison(&self, index: i32) -> Option<&str> {
+        self.run
+            .attempt_history()
+            .iter()
+            .find(|attempt| attempt.index() == index)?
+            .comparison()
+    }
+
+    /// Marks the most recent attempt in the Attempt History as invalid for the
+    /// reason provided, such as when it was run in the wrong category. The
+    /// attempt stays in the history, but can be excluded from the statistics
+    /// via [`Run::without_invalid_attempts`]. Returns `false` if there's no
+    /// attempt in the history.
+    pub fn mark_last_attempt_invalid(&mut self, reason: &str) -> bool {
+        match self.run.attempt_history_mut().last_mut() {
+            Some(attempt) => attempt.set_invalid_reason(Some(reason.into())),
+            None => return false,
+        }
+        self.run.mark_as_modified();
+        true
+    }
+
+    /// Returns for each segment how much time was lost in the current attempt
+    /// compared to the segment's best segment time for the timing method
+    /// provided. Positive values mean that time was lost, negative values
+    /// mean that the best segment was beaten. `None` is returned for segments
+    /// that were skipped or not split yet, segments directly following a
+    /// skipped segment and segments without a best segment time.
+    pub fn segment_results_vs_best(&self, method: TimingMethod) -> Vec<Option<TimeSpan>> {
+        self.run
+            .segments()
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                catch! {
+                    self.segment_time_for(index, method)? - segment.best_segment_time()[method]?
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the segment time of the current attempt for the segment with the
+    /// index and the timing method provided. This is the difference between
+    /// its split time and the split time of the segment before it. Just like
+    /// for the Best Segments, the first segment's time is its split time.
+    /// `None` is returned if the index is out of range or either of the split
+    /// times is missing, such as when a segment got skipped.
+    pub fn segment_time_for(&self, index: usize, method: TimingMethod) -> Option<TimeSpan> {
+        let segments = self.run.segments();
+        let split_time = segments.get(index)?.split_time()[method]?;
+        let previous_split_time = match index.checked_sub(1) {
+            Some(previous) => segments[previous].split_time()[method]?,
+            None => TimeSpan::zero(),
+        };
+        Some(split_time - previous_split_time)
+    }
+
+    /// Returns the indices of the segments of the current attempt whose segment
+    /// times beat their Best Segment times for the timing method provided.
+    /// This includes segments that don't have a Best Segment time yet. These
+    /// are the segments whose Best Segment times get updated when the attempt
+    /// is reset with its information being stored, so this can be used to
+    /// tell the runner about them before resetting an unfinished attempt.
+    /// Segments following a skipped segment are not included, as their
+    /// segment times span multiple segments.
+    pub fn partial_improvements(&self, method: TimingMethod) -> Vec<usize> {
+        self.run
+            .segments()
+            .iter()
+            .enumerate()
+            .filter(|&(index, segment)| {
+                self.segment_time_for(index, method).map_or(false, |time| {
+                    segment.best_segment_time()[method].map_or(true, |best| time < best)
+                })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the indices of the segments that got skipped in the current
+    /// attempt. These are the segments before the current split that don't
+    /// have a split time for any of the timing methods. Segments that weren't
+    /// reached yet are not included. If the attempt is finished, all the
+    /// segments are considered. If there's no attempt in progress, the list is
+    /// empty, as the split times are cleared when resetting.
+    pub fn skipped_segments(&self) -> Vec<usize> {
+        let reached = match self.phase {
+            NotRunning => 0,
+            Running | Paused => self.current_split_index.unwrap_or_default(),
+            Ended => self.run.len(),
+        };
+        self.run.segments()[..reached]
+            .iter()
+            .enumerate()
+            .filter(|(_, segment)| {
+                let time = segment.split_time();
+                time.real_time.is_none() && time.game_time.is_none()
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the index of the segment the elapsed time provided falls within,
+    /// based on the split times recorded in the current attempt for the timing
+    /// method provided. This can be used to map the position of a scrubber over
+    /// a finished attempt to the split to highlight. A segment spans from the
+    /// previous split time up to, but not including, its own split time.
+    /// Skipped segments don't have a split time, so their time is attributed
+    /// to the next segment that got split. If the time is negative or comes
+    /// at or after the last recorded split time, `None` is returned.
+    pub fn split_index_at_time(&self, time: TimeSpan, method: TimingMethod) -> Option<usize> {
+        if time < TimeSpan::zero() {
+            return None;
+        }
+        self.run
+            .segments()
+            .iter()
+            .position(|segment| segment.split_time()[method].map_or(false, |split| time < split))
+    }
+
+    /// Returns the time the current split needs to happen at to stay on pace
+    /// with the current comparison for the timing method provided. This is
+    /// the comparison's split time of the current split, not its segment time.
+    /// If there's no attempt in progress or the attempt is finished, `None` is
+    /// returned.
+    pub fn current_split_goal(&self, method: TimingMethod) -> Option<TimeSpan> {
+        match self.phase {
+            Running | Paused => self.comparison_time(self.current_split_index?)[method],
+            NotRunning | Ended => None,
+        }
+    }
+
+    /// Returns the time the attempt would finish at if the current pace
+    /// compared to the current comparison is maintained, for the snapshot and
+    /// timing method provided. This is the current time plus the comparison's
+    /// remaining time after the current split. If there's no attempt in
+    /// progress or the comparison is missing any of the times necessary,
+    /// `None` is returned.
+    pub fn projected_time(
+        &self,
+        snapshot: &Snapshot<'_>,
+        method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        let goal = self.current_split_goal(method)?;
+        let final_time = self.comparison_time(self.run.len() - 1)[method]?;
+        Some(snapshot.current_time()[method]? + (final_time - goal))
+    }
+
+    /// Returns how much time is remaining until the final time of the current
+    /// comparison is reached, for the snapshot and timing method provided.
+    /// Unlike [`Timer::projected_time`], this is the remaining duration rather
+    /// than the time the attempt would finish at. The value is negative if the
+    /// current time already exceeds the comparison's final time. If there's
+    /// no attempt in progress or the comparison doesn't have a final time,
+    /// `None` is returned.
+    pub fn estimated_time_remaining(
+        &self,
+        snapshot: &Snapshot<'_>,
+        method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        if !matches!(snapshot.current_phase(), Running | Paused) {
+            return None;
+        }
+        let final_time = self.comparison_time(self.run.len() - 1)[method]?;
+        Some(final_time - snapshot.current_time()[method]?)
+    }
+
+    /// Returns how far the current time has progressed into the final time of
+    /// the current comparison, for the snapshot and timing method provided.
+    /// The ratio is clamped to the range from 0 to 1, which makes it suitable
+    /// for driving a progress bar. If the comparison doesn't have a final time
+    /// or the current time is empty for the timing method, `None` is returned.
+    pub fn progress_ratio(&self, snapshot: &Snapshot<'_>, method: TimingMethod) -> Option<f32> {
+        let final_time = self.comparison_time(self.run.len() - 1)[method]?.total_seconds();
+        if final_time <= 0.0 {
+            return None;
+        }
+        let current_time = snapshot.current_time()[method]?.total_seconds();
+        Some((current_time / final_time).clamp(0.0, 1.0) as f32)
+    }
+
+    /// Subtracts the time `b` from the time `a` for the timing method
+    /// provided. This is how the components calculate deltas, so external
+    /// visualizations can use it to stay consistent with them. If either of the
+    /// times is empty for the timing method, `None` is returned.
+    pub fn subtract_times(a: Time, b: Time, method: TimingMethod) -> Option<TimeSpan> {
+        catch! { a[method]? - b[method]? }
+    }
+
+    /// Accesses the Format Options used for visualizing the times of the Timer
+    /// in a locale specific way. Currently only the
+    /// [`Timer Component`](crate::component::timer) applies them.
+    #[inline]
+    pub const fn format_options(&self) -> &FormatOptions {
+        &self.format_options
+    }
+
+    /// Sets the Format Options used for visualizing the times of the Timer in a
+    /// locale specific way. This allows for example using a comma as the
+    /// decimal separator or always showing the hours. Currently only the
+    /// [`Timer Component`](crate::component::timer) applies them, the other
+    /// components, including the segment timer of the Detailed Timer
+    /// Component, keep visualizing times the default way.
+    #[inline]
+    pub fn set_format_options(&mut self, options: FormatOptions) {
+        self.format_options = options;
+    }
+
+    /// Observes the Timer at the point in time of this call. All the
+    /// information commonly needed for visualizing the Timer is calculated at
+    /// once from a single snapshot, so it is entirely consistent.
+    pub fn observe(&self) -> Observation {
+        let snapshot = self.snapshot();
+        let method = self.current_timing_method;
+        let comparison = self.current_comparison();
+
+        let segment_index = match self.phase {
+            NotRunning => None,
+            Ended => Some(self.run.len() - 1),
+            Running | Paused => self.current_split_index,
+        };
+
+        let current_segment_time =
+            segment_index.and_then(|index| analysis::live_segment_time(&snapshot, index, method));
+        let comparison_time = segment_index.and_then(|index| self.comparison_time(index)[method]);
+        let (delta, is_live_delta) = analysis::delta::calculate(&snapshot, comparison);
+
+        let next_split_name = self
+            .current_split_index
+            .and_then(|index| self.run.segments().get(index + 1))
+            .map(|segment| segment.name().to_owned());
+
+        Observation {
+            current_time: snapshot.current_time(),
+            current_segment_time,
+            delta,
+            is_live_delta,
+            comparison_time,
+            current_split_name: self
+                .current_split()
+                .map(|segment| segment.name().to_owned()),
+            next_split_name,
         }
     }
 
@@ -464,13 +1417,45 @@ impl Timer {
         self.current_timing_method = method;
     }
 
+    /// Sets the current Timing Method to the Timing Method provided and
+    /// returns the Timer so further calls can be chained.
+    #[inline]
+    pub fn with_timing_method(&mut self, method: TimingMethod) -> &mut Self {
+        self.set_current_timing_method(method);
+        self
+    }
+
+    /// Returns the Timing Method that times are supposed to be displayed in.
+    /// Unless a separate display method is set, this is the currently selected
+    /// Timing Method. Deltas and personal bests are always based on the
+    /// currently selected Timing Method, which allows a run to be compared by
+    /// Game Time while the timer shows Real Time, for example.
+    #[inline]
+    pub fn display_method(&self) -> TimingMethod {
+        self.display_method.unwrap_or(self.current_timing_method)
+    }
+
+    /// Sets the Timing Method that times are supposed to be displayed in. If
+    /// `None` is provided, the currently selected Timing Method is used for
+    /// displaying times.
+    #[inline]
+    pub fn set_display_method(&mut self, method: Option<TimingMethod>) {
+        self.display_method = method;
+    }
+
+    /// Sets the Timing Method that times are displayed in, just like
+    /// [`Timer::set_display_method`], but returns the Timer so further calls
+    /// can be chained.
+    #[inline]
+    pub fn with_display_method(&mut self, method: Option<TimingMethod>) -> &mut Self {
+        self.set_display_method(method);
+        self
+    }
+
     /// Toggles between the `Real Time` and `Game Time` timing methods.
     #[inline]
     pub fn toggle_timing_method(&mut self) {
-        self.current_timing_method = match self.current_timing_method {
-            TimingMethod::RealTime => TimingMethod::GameTime,
-            TimingMethod::GameTime => TimingMethod::RealTime,
-        };
+        self.current_timing_method = self.current_timing_method.other();
     }
 
     /// Returns the current comparison that is being compared against. This may
@@ -480,6 +1465,123 @@ impl Timer {
         &self.current_comparison
     }
 
+    /// Returns the transient comparison if one is installed. A transient
+    /// comparison consists of one split time per segment and takes precedence
+    /// over the current comparison when calculating deltas.
+    #[inline]
+    pub fn transient_comparison(&self) -> Option<&[Time]> {
+        self.transient_comparison.as_deref()
+    }
+
+    /// Installs a transient comparison that is only kept in memory. Unlike
+    /// the comparisons of the Run, it is never stored in the splits, which
+    /// makes it suitable for racing against splits that are only temporarily
+    /// available, such as the splits of another runner. The split times are
+    /// matched to the segments by their index. Segments without a
+    /// corresponding split time are treated as having no comparison time.
+    #[inline]
+    pub fn set_transient_comparison(&mut self, splits: Vec<Time>) {
+        self.transient_comparison = Some(splits);
+    }
+
+    /// Removes the transient comparison, so that deltas are calculated
+    /// against the current comparison again.
+    #[inline]
+    pub fn clear_transient_comparison(&mut self) {
+        self.transient_comparison = None;
+    }
+
+    /// Returns the split time the current comparison has for the segment
+    /// specified. If a transient comparison is installed, its split time is
+    /// returned instead.
+    pub fn comparison_time(&self, segment_index: usize) -> Time {
+        match &self.transient_comparison {
+            Some(splits) => splits.get(segment_index).copied().unwrap_or_default(),
+            None => self
+                .run
+                .segments()
+                .get(segment_index)
+                .map(|segment| segment.comparison(&self.current_comparison))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Switches the current comparison to the
+    /// [`Best Possible`](crate::comparison::best_possible) comparison. It
+    /// represents the best possible time based on the Best Segments and is
+    /// regenerated after every attempt. If the Run doesn't have the comparison
+    /// generator, it gets added to the Run first. A custom comparison with the
+    /// same name would be overwritten by the generator, so it gets renamed to
+    /// `Best Possible (Custom)` beforehand, keeping its times.
+    pub fn set_comparison_to_best_possible(&mut self) {
+        if !self.is_generator_comparison(best_possible::NAME) {
+            self.rename_custom_comparison(best_possible::NAME);
+            self.run
+                .comparison_generators_mut()
+                .push(Box::new(BestPossible));
+            self.run.regenerate_comparisons();
+        }
+        self.current_comparison = best_possible::NAME.into();
+    }
+
+    /// Moves the custom comparison with the name provided, if there is one, to
+    /// a name that isn't taken yet, so that a comparison generator can take
+    /// its place.
+    fn rename_custom_comparison(&mut self, name: &str) {
+        let index = match self.run.custom_comparisons().iter().position(|c| c == name) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let mut new_name = format!("{name} (Custom)");
+        let mut counter = 2;
+        while self.run.validate_comparison_name(&new_name).is_err() {
+            new_name = format!("{name} (Custom {counter})");
+            counter += 1;
+        }
+
+        for segment in self.run.segments_mut() {
+            if let Some(time) = segment.comparisons_mut().remove(name) {
+                *segment.comparison_mut(&new_name) = time;
+            }
+        }
+        if self.current_comparison == name {
+            self.current_comparison = new_name.clone();
+        }
+        self.run.custom_comparisons_mut()[index] = new_name;
+    }
+
+    /// Returns whether the comparison specified has any times for the timing
+    /// method provided. Some comparisons only contain Real Time or only Game
+    /// Time, so this can be used to warn about a comparison that won't show
+    /// any deltas for the timing method. If the comparison doesn't exist,
+    /// `false` is returned.
+    pub fn comparison_has_method(&self, comparison: &str, method: TimingMethod) -> bool {
+        self.run.segments().iter().any(|segment| {
+            segment
+                .comparison_timing_method(comparison, method)
+                .is_some()
+        })
+    }
+
+    /// Returns the names of all the comparisons, in the same order the Timer
+    /// switches through them, along with their final times for the timing
+    /// method provided. The final time is the comparison's time of the last
+    /// segment, which is `None` if the comparison doesn't have one, such as
+    /// when there's no Personal Best yet.
+    pub fn comparison_summaries(&self, method: TimingMethod) -> Vec<(String, Option<TimeSpan>)> {
+        let last_segment = self.run.segments().last().unwrap();
+        self.run
+            .comparisons()
+            .map(|comparison| {
+                (
+                    comparison.to_owned(),
+                    last_segment.comparison(comparison)[method],
+                )
+            })
+            .collect()
+    }
+
     /// Tries to set the current comparison to the comparison specified. If the
     /// comparison doesn't exist `Err` is returned.
     #[inline]
@@ -493,6 +1595,18 @@ impl Timer {
         }
     }
 
+    /// Tries to set the current comparison to the comparison specified, just
+    /// like [`Timer::set_current_comparison`], but returns the Timer so further
+    /// calls can be chained. If the comparison doesn't exist `Err` is returned.
+    #[inline]
+    pub fn try_with_comparison<S: PopulateString>(
+        &mut self,
+        comparison: S,
+    ) -> Result<&mut Self, ()> {
+        self.set_current_comparison(comparison)?;
+        Ok(self)
+    }
+
     /// Accesses the split the attempt is currently on. If there's no attempt in
     /// progress or the run finished, `None` is returned instead.
     pub fn current_split(&self) -> Option<&Segment> {
@@ -515,60 +1629,391 @@ impl Timer {
         self.current_split_index
     }
 
+    /// Accesses the metadata that gets attached to the next attempt.
+    #[inline]
+    pub const fn attempt_metadata(&self) -> &BTreeMap<String, String> {
+        &self.attempt_metadata
+    }
+
+    /// Sets the metadata that gets attached to each attempt, such as the seed
+    /// of a randomizer. The metadata is captured when an attempt is started and
+    /// stored in the Attempt History once the attempt is reset. Changing it
+    /// while an attempt is in progress only affects the following attempts.
+    #[inline]
+    pub fn set_attempt_metadata(&mut self, metadata: BTreeMap<String, String>) {
+        self.attempt_metadata = metadata;
+    }
+
+    /// Returns which of the actions controlling an attempt would currently have
+    /// an effect. Splitting additionally requires the current real time to not
+    /// be negative, so an attempt with a negative start offset can't be split
+    /// before the offset has passed. This doesn't apply if countdown splits
+    /// are allowed or the current segment is marked as countdown splittable.
+    pub fn available_actions(&self) -> AvailableActions {
+        if self.is_following {
+            return AvailableActions::default();
+        }
+        let is_active = matches!(self.phase, Running | Paused);
+        AvailableActions {
+            start: self.phase == NotRunning,
+            split: self.phase == Running
+                && (self.countdown_splits
+                    || self
+                        .current_split()
+                        .map_or(false, |s| s.is_countdown_splittable())
+                    || self
+                        .current_time()
+                        .real_time
+                        .map_or(false, |t| t >= TimeSpan::zero())),
+            skip: is_active && self.current_split_index < self.run.len().checked_sub(1),
+            undo: self.phase != NotRunning && self.current_split_index > Some(0),
+            reset: self.phase != NotRunning,
+            pause: self.phase == Running,
+            resume: self.phase == Paused,
+        }
+    }
+
     /// Starts the Timer if there is no attempt in progress. If that's not the
     /// case, nothing happens.
     pub fn start(&mut self) {
-        let t0 = TimeStamp::now();
-        let t0_utc = AtomicDateTime::now();
-        if self.phase == NotRunning {
-            self.phase = Running;
-            self.current_split_index = Some(0);
-            self.attempt_started = Some(AtomicDateTime::now());
-            self.start_time = t0;
-            self.start_time_utc = t0_utc;
-            self.start_time_with_offset = self.start_time - self.run.offset();
-            self.adjusted_start_time = self.start_time_with_offset;
-            self.time_paused_at = self.run.offset();
-            self.deinitialize_game_time();
-            self.run.start_next_run();
+        if self.available_actions().start {
+            self.start_at(0, self.run.offset());
+        }
+    }
 
-            self.start_time_with_offset_utc = self.start_time_utc - self.run.offset();
-            self.adjusted_start_time_utc = self.start_time_with_offset_utc;
-            // FIXME: OnStart
-            self.save_state(Action::Start);
+    /// Starts a new attempt on the split with the given index, so that a late
+    /// segment can be practiced. The Timer starts at the Personal Best split
+    /// time of the preceding segment for the current timing method, so the
+    /// time shown matches where a full attempt would be at that point. This
+    /// only works if there's no attempt in progress, the index is in range and
+    /// the preceding segment has a Personal Best split time.
+    ///
+    /// An attempt started on a later split lacks the split times of all the
+    /// segments before it, so it is a practice attempt. Resetting it always
+    /// discards it, so it never ends up in the Attempt History, the Segment
+    /// History, the Best Segments or the Personal Best.
+    pub fn start_from_split(&mut self, index: usize) -> Result<(), StartFromSplitError> {
+        if self.phase != NotRunning {
+            return Err(StartFromSplitError::AttemptInProgress);
+        }
+        if index >= self.run.len() {
+            return Err(StartFromSplitError::OutOfRange);
         }
+
+        let offset = match index.checked_sub(1) {
+            Some(previous) => {
+                let split_time = self.run.segment(previous).personal_best_split_time();
+                split_time[self.current_timing_method]
+                    .ok_or(StartFromSplitError::NoPersonalBestSplitTime)?
+            }
+            None => self.run.offset(),
+        };
+
+        self.start_at(index, offset);
+        self.practice_attempt = index > 0;
+        Ok(())
+    }
+
+    /// Returns whether the attempt in progress got started on a later split
+    /// via [`Timer::start_from_split`]. Such an attempt is always discarded
+    /// when it gets reset.
+    #[inline]
+    pub const fn is_practice_attempt(&self) -> bool {
+        self.practice_attempt
+    }
+
+    fn start_at(&mut self, split_index: usize, offset: TimeSpan) {
+        let t0 = self.now() - self.input_latency;
+        let t0_utc = self.now_utc() - self.input_latency;
+        self.phase = Running;
+        self.current_split_index = Some(split_index);
+        self.attempt_started = Some(self.now_utc());
+        self.start_time = t0;
+        self.start_time_utc = t0_utc;
+        self.start_time_with_offset = self.start_time - offset;
+        self.adjusted_start_time = self.start_time_with_offset;
+        self.time_paused_at = offset;
+        self.loading_times = None;
+        self.current_attempt_metadata = self.attempt_metadata.clone();
+        self.pause_intervals.clear();
+        self.last_split = None;
+        self.game_time_report = None;
+        self.static_game_time = None;
+        self.split_times_utc.clear();
+        self.run.start_next_run();
+
+        self.start_time_with_offset_utc = self.start_time_utc - offset;
+        self.adjusted_start_time_utc = self.start_time_with_offset_utc;
+        // FIXME: OnStart
+        let logged = if split_index == 0 {
+            LoggedAction::Action(Action::Start)
+        } else {
+            LoggedAction::StartFromSplit(split_index)
+        };
+        self.record_logged_action(Action::Start, logged);
     }
 
     /// If an attempt is in progress, stores the current time as the time of the
-    /// current split. The attempt ends if the last split time is stored.
-    pub fn split(&mut self) {
-        let current_time = self.current_time();
-        if self.phase == Running
-            && current_time
-                .real_time
-                .map_or(false, |t| t >= TimeSpan::zero())
-        {
-            // FIXME: We shouldn't need to collect here.
-            let variables = self
-                .run
-                .metadata()
-                .custom_variables()
-                .map(|(k, v)| (k.to_owned(), v.value.clone()))
-                .collect();
-            let segment = self.current_split_mut().unwrap();
-
-            segment.set_split_time(current_time);
-            *segment.variables_mut() = variables;
-
-            *self.current_split_index.as_mut().unwrap() += 1;
-            if Some(self.run.len()) == self.current_split_index {
-                self.phase = Ended;
-                self.attempt_ended = Some(AtomicDateTime::now());
+    /// current split. The attempt ends if the last split time is stored. If a
+    /// split filter is set and it rejects the split, the split happens within
+    /// the split debounce interval of the previous split or the segment would
+    /// be shorter than the minimum segment time, nothing happens. Returns
+    /// whether the split was recorded.
+    pub fn split(&mut self) -> bool {
+        if !self.available_actions().split {
+            return false;
+        }
+        let now = self.now();
+        if let (Some(min_interval), Some(last_split)) = (self.split_debounce, self.last_split) {
+            if now - last_split < min_interval {
+                return false;
             }
-            self.run.mark_as_modified();
-            self.save_state(Action::Split);
-            // FIXME: OnSplit
         }
+        let snapshot = self.snapshot();
+        if let Some(SplitFilter(filter)) = self.split_filter {
+            if !filter(&snapshot) {
+                return false;
+            }
+        }
+        let mut current_time = snapshot.current_time();
+        if self.input_latency != TimeSpan::zero() {
+            current_time.real_time = current_time.real_time.map(|t| t - self.input_latency);
+            if !self.is_game_time_paused() {
+                current_time.game_time = current_time.game_time.map(|t| t - self.input_latency);
+            }
+        }
+        if let Some(final_time) = self.final_time_override {
+            if self.current_split_index == Some(self.run.len() - 1) {
+                current_time.real_time = final_time.real_time.or(current_time.real_time);
+                current_time.game_time = final_time.game_time.or(current_time.game_time);
+            }
+        }
+        if let Some(min_segment_time) = self.min_segment_time {
+            let method = self.current_timing_method;
+            let previous_split_time = self.run.segments()[..self.current_split_index.unwrap()]
+                .iter()
+                .rev()
+                .find_map(|segment| segment.split_time()[method])
+                .unwrap_or_default();
+            if current_time[method].map_or(false, |t| t - previous_split_time < min_segment_time) {
+                return false;
+            }
+        }
+        // FIXME: We shouldn't need to collect here.
+        let variables = self
+            .run
+            .metadata()
+            .custom_variables()
+            .map(|(k, v)| (k.to_owned(), v.value.clone()))
+            .collect();
+        let segment = self.current_split_mut().unwrap();
+
+        segment.set_split_time(current_time);
+        *segment.variables_mut() = variables;
+
+        let split_time_utc = self.now_utc() - self.input_latency;
+        self.set_split_time_utc(self.current_split_index.unwrap(), Some(split_time_utc));
+        *self.current_split_index.as_mut().unwrap() += 1;
+        if Some(self.run.len()) == self.current_split_index {
+            self.phase = Ended;
+            self.attempt_ended = Some(self.now_utc());
+        }
+        self.last_split = Some(now);
+        self.debug_assert_split_index();
+        self.run.mark_as_modified();
+        self.record_action(Action::Split);
+        // FIXME: OnSplit
+        true
+    }
+
+    /// Returns the point in time the current attempt started at. If there's no
+    /// attempt in progress, `None` is returned.
+    pub const fn attempt_started(&self) -> Option<AtomicDateTime> {
+        match self.phase {
+            NotRunning => None,
+            Running | Paused | Ended => self.attempt_started,
+        }
+    }
+
+    /// Returns the point in time the split with the index provided happened at
+    /// during the current attempt. This can be used to align the splits with a
+    /// recording of the attempt. `None` is returned if the split didn't happen
+    /// yet or got skipped.
+    pub fn split_datetime_utc(&self, index: usize) -> Option<AtomicDateTime> {
+        self.split_times_utc.get(index).copied().flatten()
+    }
+
+    fn set_split_time_utc(&mut self, index: usize, time: Option<AtomicDateTime>) {
+        if self.split_times_utc.len() <= index {
+            self.split_times_utc.resize(index + 1, None);
+        }
+        self.split_times_utc[index] = time;
+    }
+
+    /// Returns the minimum time a segment needs to take for a split to be
+    /// recorded.
+    #[inline]
+    pub const fn min_segment_time(&self) -> Option<TimeSpan> {
+        self.min_segment_time
+    }
+
+    /// Sets the minimum time a segment needs to take for a split to be
+    /// recorded, based on the current timing method. Splits that would result
+    /// in a shorter segment are ignored. This prevents two splits happening at
+    /// virtually the same time, such as on the same frame, from resulting in
+    /// bogus Best Segments. `None` disables the check, which is the default.
+    #[inline]
+    pub fn set_min_segment_time(&mut self, min: Option<TimeSpan>) {
+        self.min_segment_time = min;
+    }
+
+    /// Returns the time that gets recorded for the final split instead of the
+    /// current time, if there is any.
+    #[inline]
+    pub const fn final_time_override(&self) -> Option<Time> {
+        self.final_time_override
+    }
+
+    /// Sets the time that gets recorded for the final split instead of the
+    /// current time, which is useful for categories where the official final
+    /// time is read from the game. While set, it takes precedence over the
+    /// running clock, the input latency and the Game Time reported so far,
+    /// but only for the final split. Only the timing methods the override has
+    /// a time for are overridden, the others keep the current time. The
+    /// override is cleared when the attempt is reset.
+    #[inline]
+    pub fn set_final_time_override(&mut self, time: Option<Time>) {
+        self.final_time_override = time;
+    }
+
+    /// Returns for how long the Game Time needs to stay the same on the final
+    /// split for [`Timer::poll_finish`] to split it, if finish detection is
+    /// enabled.
+    #[inline]
+    pub const fn finish_detection(&self) -> Option<TimeSpan> {
+        self.finish_detection
+    }
+
+    /// Sets for how long the Game Time needs to stay the same on the final
+    /// split for [`Timer::poll_finish`] to split it. This is meant for games
+    /// that stop their in-game timer at the end of a run without providing
+    /// any other way of detecting the finish. `None` disables the finish
+    /// detection, which is the default.
+    #[inline]
+    pub fn set_finish_detection(&mut self, window: Option<TimeSpan>) {
+        self.finish_detection = window;
+        self.static_game_time = None;
+    }
+
+    /// Checks whether the attempt got finished according to the finish
+    /// detection configured via [`Timer::set_finish_detection`]. This needs to
+    /// be called periodically by the host, such as whenever the Game Time gets
+    /// updated. If the Timer is on the final split and the Game Time hasn't
+    /// changed for at least the configured amount of time, the final split is
+    /// split. None of the other splits are ever split by this. Returns whether
+    /// the final split got split.
+    pub fn poll_finish(&mut self) -> bool {
+        let is_on_final_split =
+            self.phase == Running && self.current_split_index == Some(self.run.len() - 1);
+        let (window, game_time) = match (self.finish_detection, self.current_time().game_time) {
+            (Some(window), Some(game_time)) if is_on_final_split => (window, game_time),
+            _ => {
+                self.static_game_time = None;
+                return false;
+            }
+        };
+
+        let now = self.now();
+        match self.static_game_time {
+            Some((static_game_time, since)) if static_game_time == game_time => {
+                if now - since >= window && self.split() {
+                    self.static_game_time = None;
+                    return true;
+                }
+            }
+            _ => self.static_game_time = Some((game_time, now)),
+        }
+        false
+    }
+
+    /// Returns whether all segments can be split while the Timer is still
+    /// counting down a negative start offset.
+    #[inline]
+    pub const fn allows_countdown_splits(&self) -> bool {
+        self.countdown_splits
+    }
+
+    /// Sets whether all segments can be split while the Timer is still
+    /// counting down a negative start offset, which is useful for categories
+    /// that legitimately split during the countdown. If only some of the
+    /// segments should be splittable that early, mark them individually via
+    /// [`Segment::set_countdown_splittable`] instead. This is disabled by
+    /// default.
+    #[inline]
+    pub fn set_allow_countdown_splits(&mut self, allow: bool) {
+        self.countdown_splits = allow;
+    }
+
+    /// Sets a filter that gets consulted whenever a split is about to be
+    /// recorded. It receives a snapshot of the Timer right before the split.
+    /// If it returns `false`, the split is not recorded. This can be used to
+    /// ignore accidental double splits, for example. By default all splits are
+    /// allowed. See [`SplitFilterFunc`] for why the filter can't capture any
+    /// state of its own.
+    pub fn set_split_filter(&mut self, filter: SplitFilterFunc) {
+        self.split_filter = Some(SplitFilter(filter));
+    }
+
+    /// Removes the split filter, so that all splits are allowed again.
+    pub fn clear_split_filter(&mut self) {
+        self.split_filter = None;
+    }
+
+    /// Returns whether practice mode is enabled. While it is enabled, resetting
+    /// an attempt never updates the Attempt History, the Segment History, the
+    /// Best Segments or the Personal Best.
+    #[inline]
+    pub const fn is_practice_mode(&self) -> bool {
+        self.practice_mode
+    }
+
+    /// Enables or disables practice mode. While it is enabled, attempts are
+    /// timed as usual, but resetting them never stores anything in the Run,
+    /// so practice attempts don't affect any statistics or comparisons.
+    #[inline]
+    pub fn set_practice_mode(&mut self, on: bool) {
+        self.practice_mode = on;
+    }
+
+    /// Returns the input latency that start and split times are corrected by.
+    #[inline]
+    pub const fn input_latency(&self) -> TimeSpan {
+        self.input_latency
+    }
+
+    /// Sets the input latency, i.e. how long it takes from physically pressing
+    /// a button until the Timer gets notified about it. Attempts are then
+    /// started that much earlier and split times are recorded that much
+    /// earlier, so that known controller or input delays are accounted for.
+    #[inline]
+    pub fn set_input_latency(&mut self, latency: TimeSpan) {
+        self.input_latency = latency;
+    }
+
+    /// Returns the minimum interval between two splits. Splits happening
+    /// within this interval of the previous split are ignored.
+    #[inline]
+    pub const fn split_debounce(&self) -> Option<TimeSpan> {
+        self.split_debounce
+    }
+
+    /// Sets the minimum interval between two splits, so that accidentally
+    /// splitting twice only records a single split. Splits happening within
+    /// this interval of the previous split are ignored. The first split of an
+    /// attempt is never ignored. `None` disables the debouncing.
+    #[inline]
+    pub fn set_split_debounce(&mut self, min_interval: Option<TimeSpan>) {
+        self.split_debounce = min_interval;
     }
 
     ///
@@ -578,6 +2023,40 @@ impl Timer {
     pub fn set_on_timer_change(&mut self, func: OnTimerChangeFunc) {
         self.on_timer_change = OnTimerChange(func);
     }
+    fn record_action(&mut self, action: Action) {
+        self.record_logged_action(action, LoggedAction::Action(action));
+    }
+
+    /// Records the action with a more detailed entry in the action log, so
+    /// that replaying the log reproduces the action exactly.
+    fn record_logged_action(&mut self, action: Action, logged: LoggedAction) {
+        self.last_action = action;
+        self.last_action_time = self.now();
+        self.push_to_action_log(logged);
+        self.save_state(action);
+    }
+
+    fn log_action(&mut self, action: Action) {
+        self.last_action = action;
+        self.last_action_time = self.now();
+        self.push_to_action_log(LoggedAction::Action(action));
+    }
+
+    /// The entries are stored with their offset from the start of the attempt,
+    /// so that the log can be replayed in a different process.
+    fn push_to_action_log(&mut self, action: LoggedAction) {
+        let capacity = self.action_log_capacity;
+        if capacity == 0 {
+            return;
+        }
+        let offset = self.now() - self.start_time;
+        if let Some(log) = &mut self.action_log {
+            let excess = (log.len() + 1).saturating_sub(capacity);
+            log.drain(..excess);
+            log.push((offset, action));
+        }
+    }
+
     ///
     pub fn save_state(&self, action: Action) {
         let func = self.on_timer_change.0;
@@ -585,38 +2064,215 @@ impl Timer {
     }
     ///
     pub fn timer_state(&self, action: Action) -> TimerState {
+        self.timer_state_with_improvement(action, None)
+    }
+
+    fn timer_state_with_improvement(
+        &self,
+        action: Action,
+        personal_best_improvement: Option<TimeSpan>,
+    ) -> TimerState {
         let mut state: TimerState = self.into();
         state.action = action;
+        state.personal_best_improvement = personal_best_improvement.map(ts_to_f64);
+        if let Some(decimals) = self.state_precision {
+            state.round_times(decimals);
+        }
         state
     }
-    ///
-    pub fn replace_state(&mut self, state: &TimerState) {
-        if state.splits.len() != self.run.segments().len() {
-            panic!(
-                "inconsistent state, run has {} segments, state has {} segments",
-                self.run.segments().len(),
-                state.splits.len()
-            );
+
+    /// Returns the amount of decimal places the times of the states created
+    /// via [`Timer::timer_state`] are rounded to. `None` means that they are
+    /// not rounded at all.
+    #[inline]
+    pub const fn state_precision(&self) -> Option<u8> {
+        self.state_precision
+    }
+
+    /// Sets the amount of decimal places the times of the states created via
+    /// [`Timer::timer_state`] are rounded to. The times are stored in seconds,
+    /// so the default of 3 keeps millisecond precision while avoiding
+    /// floating point noise in the serialized states. `None` disables the
+    /// rounding. Restoring a state is not affected by this.
+    #[inline]
+    pub fn set_state_precision(&mut self, decimals: Option<u8>) {
+        self.state_precision = decimals;
+    }
+
+    /// Restores the state of an attempt that was previously retrieved via
+    /// [`Timer::timer_state`]. If the state doesn't have the same amount of
+    /// splits as the Run has segments or any of its timestamps can't be
+    /// parsed, the issue is returned and the Timer is left untouched.
+    pub fn replace_state(&mut self, state: &TimerState) -> Result<(), StateIssue> {
+        if state.splits.len() != self.run.len() {
+            return Err(StateIssue::SegmentCountMismatch {
+                expected: self.run.len(),
+                actual: state.splits.len(),
+            });
         }
+        let attempt_started = state
+            .attempt_started
+            .as_ref()
+            .map(|x| restore_timestamp("attempt_started", x))
+            .transpose()?;
+        let attempt_ended = state
+            .attempt_ended
+            .as_ref()
+            .map(|x| restore_timestamp("attempt_ended", x))
+            .transpose()?;
+        let start_time_utc = restore_timestamp("start_time_utc", &state.start_time_utc)?;
+        let start_time_with_offset_utc = restore_timestamp(
+            "start_time_with_offset_utc",
+            &state.start_time_with_offset_utc,
+        )?;
+        let adjusted_start_time_utc =
+            restore_timestamp("adjusted_start_time_utc", &state.adjusted_start_time_utc)?;
+        let pause_intervals = state
+            .pause_intervals
+            .iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let split_times_utc = state
+            .split_times_utc
+            .iter()
+            .map(|time| {
+                time.as_ref()
+                    .map(|time| restore_timestamp("split_times_utc", time))
+                    .transpose()
+            })
+            .collect::<Result<_, _>>()?;
+
         for (i, split) in state.splits.iter().enumerate() {
             self.run.segment_mut(i).set_split_time(split.into());
         }
         self.phase = state.phase.as_str().into();
         self.current_split_index = state.current_split_index;
         self.current_timing_method = state.current_timing_method;
+        self.display_method = state.display_method;
         self.current_comparison = state.current_comparison.clone();
-        self.attempt_started = state.attempt_started.as_ref().map(|x| x.into());
-        self.attempt_ended = state.attempt_started.as_ref().map(|x| x.into());
-        self.start_time = TimeStamp::now();
-        self.start_time_with_offset = self.start_time;
-        self.adjusted_start_time = self.start_time;
+        self.attempt_started = attempt_started;
+        self.attempt_ended = attempt_ended;
+        // The state only stores the UTC start times, so the monotonic ones get
+        // derived from them. Otherwise the Real Time would start over from
+        // zero and resuming the Game Time would shift the loading times.
+        let now = self.now();
+        let now_utc = self.now_utc();
+        self.start_time = now - (now_utc - start_time_utc);
+        self.start_time_with_offset = now - (now_utc - start_time_with_offset_utc);
+        self.adjusted_start_time = now - (now_utc - adjusted_start_time_utc);
+        self.game_time_report = None;
         self.time_paused_at = state.time_paused_at.into();
         self.is_game_time_paused = state.is_game_time_paused;
         self.game_time_pause_time = state.game_time_pause_time.map(|x| x.into());
         self.loading_times = state.loading_times.map(|x| x.into());
-        self.start_time_utc = (&state.start_time_utc).into();
-        self.start_time_with_offset_utc = (&state.start_time_with_offset_utc).into();
-        self.adjusted_start_time_utc = (&state.adjusted_start_time_utc).into();
+        self.start_time_utc = start_time_utc;
+        self.start_time_with_offset_utc = start_time_with_offset_utc;
+        self.adjusted_start_time_utc = adjusted_start_time_utc;
+        self.pause_intervals = pause_intervals;
+        self.split_times_utc = split_times_utc;
+        self.practice_attempt = state.practice_attempt;
+        Ok(())
+    }
+
+    /// Applies a state retrieved via [`Timer::timer_state`] from a remote Timer,
+    /// such as one running in a different process, and turns this Timer into a
+    /// follower of it. A follower is a passive mirror of the remote Timer. Its
+    /// current time is always derived from the UTC timestamps of the remote
+    /// Timer instead of the local clock, so it keeps showing the same time as
+    /// the remote Timer between two states. None of the actions that control
+    /// an attempt have any effect on a follower, so the attempt only changes
+    /// when the next remote state is applied. If the state can't be applied,
+    /// the issue is returned and the Timer is left untouched. Use
+    /// [`Timer::stop_following`] to control the Timer locally again.
+    pub fn apply_remote_state(&mut self, state: &TimerState) -> Result<(), StateIssue> {
+        self.replace_state(state)?;
+        self.is_following = true;
+        Ok(())
+    }
+
+    /// Returns whether the Timer is a follower of a remote Timer, driven only
+    /// by the states applied via [`Timer::apply_remote_state`].
+    #[inline]
+    pub const fn is_following(&self) -> bool {
+        self.is_following
+    }
+
+    /// Stops following a remote Timer, so that the Timer can be controlled
+    /// locally again. The attempt continues from the last applied state.
+    #[inline]
+    pub fn stop_following(&mut self) {
+        self.is_following = false;
+    }
+
+    /// Sets the split times of the current attempt all at once, which is useful
+    /// for loading the splits of an attempt from elsewhere, such as the result
+    /// of a race. There needs to be exactly one time per segment and an attempt
+    /// needs to be in progress or finished. While the attempt is still in
+    /// progress, the splits it hasn't reached yet need to be left empty.
+    /// Otherwise the error is returned and the split times are left untouched.
+    /// The points in time of all the splits whose time changes are cleared, as
+    /// they are not known for times coming from elsewhere.
+    pub fn set_split_times(&mut self, times: &[Time]) -> Result<(), SetSplitTimesError> {
+        if self.phase == NotRunning {
+            return Err(SetSplitTimesError::NoAttempt);
+        }
+        if times.len() != self.run.len() {
+            return Err(SetSplitTimesError::TimeCountMismatch);
+        }
+        if self.phase != Ended {
+            let current = self.current_split_index.unwrap();
+            if let Some(index) = (current..times.len()).find(|&i| times[i] != Time::default()) {
+                return Err(SetSplitTimesError::SplitNotReached { index });
+            }
+        }
+
+        for (index, &time) in times.iter().enumerate() {
+            if self.run.segment(index).split_time() != time {
+                self.run.segment_mut(index).set_split_time(time);
+                self.set_split_time_utc(index, None);
+            }
+        }
+        self.run.mark_as_modified();
+
+        Ok(())
+    }
+
+    /// Checks whether any split time of the current attempt is smaller than
+    /// the split time of a segment before it for the timing method provided.
+    /// This may happen when split times are edited or imported and results in
+    /// negative segment times. Skipped segments are ignored.
+    pub fn has_non_monotonic_splits(&self, method: TimingMethod) -> bool {
+        let mut previous_time = None;
+        self.run.segments().iter().any(|segment| {
+            let time = segment.split_time()[method];
+            let is_decreasing = catch! { time? < previous_time? }.unwrap_or_default();
+            previous_time = time.or(previous_time);
+            is_decreasing
+        })
+    }
+
+    /// Clamps every split time of the current attempt that is smaller than the
+    /// split time of a segment before it to that split time, for all the
+    /// timing methods. This way none of the segment times of the attempt are
+    /// negative. Skipped segments are left untouched.
+    pub fn fix_non_monotonic_splits(&mut self) {
+        let mut modified = false;
+        for method in TimingMethod::all() {
+            let mut previous_time = None;
+            for segment in self.run.segments_mut() {
+                let time = &mut segment.split_time_mut()[method];
+                if let (Some(t), Some(previous)) = (time.as_mut(), previous_time) {
+                    if *t < previous {
+                        *t = previous;
+                        modified = true;
+                    }
+                }
+                previous_time = time.or(previous_time);
+            }
+        }
+        if modified {
+            self.run.mark_as_modified();
+        }
     }
 
     /// Starts a new attempt or stores the current time as the time of the
@@ -632,14 +2288,14 @@ impl Timer {
     /// Skips the current split if an attempt is in progress and the
     /// current split is not the last split.
     pub fn skip_split(&mut self) {
-        if (self.phase == Running || self.phase == Paused)
-            && self.current_split_index < self.run.len().checked_sub(1)
-        {
+        if self.available_actions().skip {
             self.current_split_mut().unwrap().clear_split_info();
+            self.set_split_time_utc(self.current_split_index.unwrap(), None);
 
             self.current_split_index = self.current_split_index.map(|i| i + 1);
+            self.debug_assert_split_index();
             self.run.mark_as_modified();
-            self.save_state(Action::Skip);
+            self.record_action(Action::Skip);
             // FIXME: OnSkipSplit
         }
     }
@@ -648,61 +2304,136 @@ impl Timer {
     /// and there is a previous split. The Timer Phase also switches to
     /// `Running` if it previously was `Ended`.
     pub fn undo_split(&mut self) {
-        if self.phase != NotRunning && self.current_split_index > Some(0) {
+        if self.available_actions().undo {
             if self.phase == Ended {
                 self.phase = Running;
             }
             self.current_split_index = self.current_split_index.map(|i| i - 1);
 
             self.current_split_mut().unwrap().clear_split_info();
+            self.set_split_time_utc(self.current_split_index.unwrap(), None);
 
+            self.debug_assert_split_index();
             self.run.mark_as_modified();
-            self.save_state(Action::Undo);
+            self.record_action(Action::Undo);
             // FIXME: OnUndoSplit
         }
     }
 
+    /// Checks that the index of the current split is consistent with the Timer
+    /// Phase. While an attempt is in progress, the index always needs to point
+    /// at a segment, so that there is a current split. Once the attempt ended,
+    /// the index is one past the last segment.
+    fn debug_assert_split_index(&self) {
+        match self.phase {
+            NotRunning => debug_assert_eq!(self.current_split_index, None),
+            Running | Paused => debug_assert!(
+                self.current_split_index < Some(self.run.len()),
+                "split index {:?} out of range for {} segments",
+                self.current_split_index,
+                self.run.len(),
+            ),
+            Ended => debug_assert_eq!(self.current_split_index, Some(self.run.len())),
+        }
+    }
+
     /// Resets the current attempt if there is one in progress. If the splits
     /// are to be updated, all the information of the current attempt is stored
     /// in the Run's history. Otherwise the current attempt's information is
-    /// discarded.
-    pub fn reset(&mut self, update_splits: bool) {
-        if self.phase != NotRunning {
-            self.reset_state(update_splits);
+    /// discarded, which is always the case in practice mode. Returns whether
+    /// an attempt was actually reset, which is not the case if there was no
+    /// attempt in progress.
+    pub fn reset(&mut self, update_splits: bool) -> bool {
+        self.reset_with(update_splits.into())
+    }
+
+    /// Resets the current attempt if there is one in progress. The kind of
+    /// reset determines how much of the current attempt's information is
+    /// stored in the Run. While practice mode is enabled or if the attempt got
+    /// started on a later split, the attempt is always discarded. Returns
+    /// whether an attempt was actually reset, which is not the case if there
+    /// was no attempt in progress. The callback registered via
+    /// [`Timer::set_on_timer_change`] only gets notified with states that
+    /// describe the attempt either entirely before or entirely after the
+    /// reset.
+    pub fn reset_with(&mut self, kind: ResetKind) -> bool {
+        if self.available_actions().reset {
+            let kind = if self.practice_mode || self.practice_attempt {
+                ResetKind::Discard
+            } else {
+                kind
+            };
+            self.reset_state(kind);
             self.reset_splits();
-            self.save_state(Action::Reset);
+            self.record_logged_action(Action::Reset, LoggedAction::Reset(kind));
+            true
+        } else {
+            false
         }
     }
 
     /// Resets the current attempt if there is one in progress. The splits are
     /// updated such that the current attempt's split times are being stored as
-    /// the new Personal Best.
+    /// the new Personal Best. An attempt started on a later split lacks the
+    /// earlier split times, so it is discarded instead.
     pub fn reset_and_set_attempt_as_pb(&mut self) {
         if self.phase != NotRunning {
-            self.reset_state(true);
-            self.set_run_as_pb();
+            if self.practice_attempt {
+                self.reset_state(ResetKind::Discard);
+            } else {
+                self.reset_state(ResetKind::SaveAll);
+                self.set_run_as_pb();
+            }
             self.reset_splits();
         }
     }
 
-    fn reset_state(&mut self, update_times: bool) {
+    fn reset_state(&mut self, kind: ResetKind) {
         if self.phase != Ended {
-            self.attempt_ended = Some(AtomicDateTime::now());
+            self.attempt_ended = Some(self.now_utc());
+        }
+        self.end_pause_interval(self.attempt_ended);
+        self.unpause_game_time();
+
+        // The state the subscriber gets notified with about a new Personal
+        // Best needs to describe the attempt before any part of it is moved
+        // into the splits or cleared.
+        let new_pb_state = match kind {
+            ResetKind::SaveAll => self.new_personal_best_state(),
+            _ => None,
+        };
+        // The loading times of the attempt are kept in the Attempt History.
+        let loading_times = self.loading_times;
+
+        match kind {
+            ResetKind::Discard => {}
+            ResetKind::SaveHistoryOnly => {
+                self.update_attempt_history(loading_times);
+                self.update_segment_history();
+            }
+            ResetKind::SaveAll => {
+                self.update_attempt_history(loading_times);
+                self.update_best_segments();
+                if new_pb_state.is_some() {
+                    self.set_run_as_pb();
+                }
+                self.update_segment_history();
+            }
         }
-        self.resume_game_time();
-        self.set_loading_times(TimeSpan::zero());
+        self.loading_times = Some(TimeSpan::zero());
 
-        if update_times {
-            self.update_attempt_history();
-            self.update_best_segments();
-            self.update_pb_splits();
-            self.update_segment_history();
+        if let Some(state) = new_pb_state {
+            self.log_action(Action::NewPersonalBest);
+            (self.on_timer_change.0)(&state);
         }
     }
 
     fn reset_splits(&mut self) {
         self.phase = NotRunning;
         self.current_split_index = None;
+        self.final_time_override = None;
+        self.practice_attempt = false;
+        self.split_times_utc.clear();
 
         // Reset Splits
         for segment in self.run.segments_mut() {
@@ -717,25 +2448,132 @@ impl Timer {
 
     /// Pauses an active attempt that is not paused.
     pub fn pause(&mut self) {
-        if self.phase == Running {
+        self.pause_with_label("");
+    }
+
+    /// Pauses an active attempt that is not paused and labels the pause with
+    /// the reason for it, such as a loading screen or a cutscene. The labeled
+    /// pause intervals are stored in the Attempt History.
+    pub fn pause_with_label(&mut self, label: &str) {
+        if self.available_actions().pause {
             self.time_paused_at = self.current_time().real_time.unwrap();
+            self.pause_intervals
+                .push(PauseInterval::new(label, self.now_utc(), None));
             self.phase = Paused;
-            self.save_state(Action::Pause);
+            self.record_action(Action::Pause);
             // FIXME: OnPause
         }
     }
 
     /// Resumes an attempt that is paused.
     pub fn resume(&mut self) {
-        if self.phase == Paused {
-            self.adjusted_start_time = TimeStamp::now() - self.time_paused_at;
-            self.adjusted_start_time_utc = AtomicDateTime::now() - self.time_paused_at;
+        if self.available_actions().resume {
+            self.adjusted_start_time = self.now() - self.time_paused_at;
+            self.adjusted_start_time_utc = self.now_utc() - self.time_paused_at;
+            self.end_pause_interval(Some(self.now_utc()));
             self.phase = Running;
-            self.save_state(Action::Resume);
+            self.record_action(Action::Resume);
             // FIXME: OnResume
         }
     }
 
+    /// Accesses the pause intervals of the current attempt. If the attempt is
+    /// currently paused, the last interval has not ended yet.
+    #[inline]
+    pub fn pause_intervals(&self) -> &[PauseInterval] {
+        &self.pause_intervals
+    }
+
+    fn end_pause_interval(&mut self, ended: Option<AtomicDateTime>) {
+        if let Some(interval) = self.pause_intervals.last_mut() {
+            if interval.ended().is_none() {
+                interval.set_ended(ended);
+            }
+        }
+    }
+
+    /// Returns the last action that got performed on the Timer. This is
+    /// [`Action::None`] if no action has been performed yet.
+    #[inline]
+    pub const fn last_action(&self) -> Action {
+        self.last_action
+    }
+
+    /// Returns how much time passed since the last action got performed on the
+    /// Timer. This can be used to hide an overlay after a period of
+    /// inactivity. If no action has been performed yet, zero is returned.
+    pub fn elapsed_since_last_action(&self) -> TimeSpan {
+        if self.last_action == Action::None {
+            return TimeSpan::zero();
+        }
+        self.now() - self.last_action_time
+    }
+
+    /// Returns the amount of time without any action after which a running
+    /// attempt gets paused automatically by [`Timer::update_idle`].
+    #[inline]
+    pub const fn idle_timeout(&self) -> Option<TimeSpan> {
+        self.idle_timeout
+    }
+
+    /// Sets the amount of time without any action after which a running
+    /// attempt gets paused automatically by [`Timer::update_idle`]. This
+    /// prevents the Timer from running away when the runner walks away from
+    /// an unattended session. `None` disables the automatic pausing.
+    #[inline]
+    pub fn set_idle_timeout(&mut self, timeout: Option<TimeSpan>) {
+        self.idle_timeout = timeout;
+    }
+
+    /// Pauses the attempt if it is running and no action has occurred within
+    /// the idle timeout. This needs to be called periodically for the idle
+    /// timeout to have any effect.
+    pub fn update_idle(&mut self) {
+        if let Some(timeout) = self.idle_timeout {
+            if self.phase == Running && self.now() - self.last_action_time >= timeout {
+                self.pause();
+            }
+        }
+    }
+
+    /// Starts recording every action performed on the Timer, as well as every
+    /// change to the Game Time, along with how long after the start of the
+    /// attempt it happened. The log can then be attached to bug reports in
+    /// order to reproduce the actions that led to an issue via
+    /// [`Timer::replay`]. If the action log is already enabled, the actions
+    /// recorded so far are kept.
+    pub fn enable_action_log(&mut self) {
+        self.action_log.get_or_insert_with(Vec::new);
+    }
+
+    /// Stops recording actions and discards the actions recorded so far.
+    pub fn disable_action_log(&mut self) {
+        self.action_log = None;
+    }
+
+    /// Returns all the actions recorded since the action log got enabled,
+    /// from oldest to newest. This is empty if the action log is disabled.
+    pub fn action_log(&self) -> &[(TimeSpan, LoggedAction)] {
+        self.action_log.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the maximum amount of actions the action log keeps.
+    #[inline]
+    pub const fn action_log_capacity(&self) -> usize {
+        self.action_log_capacity
+    }
+
+    /// Sets the maximum amount of actions the action log keeps in order to
+    /// bound its memory usage. Once the log is full, the oldest actions get
+    /// dropped.
+    pub fn set_action_log_capacity(&mut self, capacity: usize) {
+        self.action_log_capacity = capacity;
+        if let Some(log) = &mut self.action_log {
+            let excess = log.len().saturating_sub(capacity);
+            log.drain(..excess);
+        }
+    }
+
     /// Toggles an active attempt between `Paused` and `Running`.
     pub fn toggle_pause(&mut self) {
         match self.phase {
@@ -793,32 +2631,36 @@ impl Timer {
         // FIXME: OnUndoAllPauses
     }
 
-    /// Switches the current comparison to the next comparison in the list.
+    /// Switches the current comparison to the next comparison in the list. If
+    /// the current comparison doesn't exist anymore, the Personal Best
+    /// comparison is selected instead.
     pub fn switch_to_next_comparison(&mut self) {
-        let mut comparisons = self.run.comparisons();
-        let len = comparisons.len();
-        let index = comparisons
-            .position(|c| c == self.current_comparison)
-            .unwrap();
-        let index = (index + 1) % len;
-        self.current_comparison = self.run.comparisons().nth(index).unwrap().to_owned();
+        self.switch_comparison(|index, len| (index + 1) % len);
 
         // FIXME: OnNextComparison
     }
 
     /// Switches the current comparison to the previous comparison in the list.
+    /// If the current comparison doesn't exist anymore, the Personal Best
+    /// comparison is selected instead.
     pub fn switch_to_previous_comparison(&mut self) {
-        let mut comparisons = self.run.comparisons();
-        let len = comparisons.len();
-        let index = comparisons
-            .position(|c| c == self.current_comparison)
-            .unwrap();
-        let index = (index + len - 1) % len;
-        self.current_comparison = self.run.comparisons().nth(index).unwrap().to_owned();
+        self.switch_comparison(|index, len| (index + len - 1) % len);
 
         // FIXME: OnPreviousComparison
     }
 
+    fn switch_comparison(&mut self, next_index: impl FnOnce(usize, usize) -> usize) {
+        let comparisons: Vec<&str> = self.run.comparisons().collect();
+        let comparison = match comparisons
+            .iter()
+            .position(|&c| c == self.current_comparison)
+        {
+            Some(index) => comparisons[next_index(index, comparisons.len())],
+            None => personal_best::NAME,
+        };
+        comparison.populate(&mut self.current_comparison);
+    }
+
     /// Returns the total duration of the current attempt. This is not affected
     /// by the start offset of the run. So if the start offset is -10s and the
     /// `start()` method was called 2s ago, the current time is -8s but the
@@ -827,8 +2669,8 @@ impl Timer {
     /// duration only counts the time the Timer Phase has actually been
     /// `Running`.
     pub fn current_attempt_duration(&self) -> TimeSpan {
-        let t0 = TimeStamp::now();
-        let t0_utc = AtomicDateTime::now();
+        let t0 = self.now();
+        let t0_utc = self.now_utc();
         let ts = match self.current_phase() {
             NotRunning => TimeSpan::zero(),
             Paused | Running => t0 - self.start_time,
@@ -848,11 +2690,30 @@ impl Timer {
         }
     }
 
+    /// Returns how long the game has actually been played during the current
+    /// attempt. This is the [`current_attempt_duration`](Timer::current_attempt_duration)
+    /// without the loading times, including the loading times that have
+    /// accumulated while the Game Time is currently paused. If Game Time isn't
+    /// initialized, there are no loading times to exclude. If there's no
+    /// attempt in progress, zero is returned.
+    pub fn active_play_time(&self) -> TimeSpan {
+        if self.phase == NotRunning {
+            return TimeSpan::zero();
+        }
+        let time = self.current_time();
+        let loading_times = if self.is_game_time_initialized() {
+            catch! { time.real_time? - time.game_time? }.unwrap_or_else(|| self.loading_times())
+        } else {
+            TimeSpan::zero()
+        };
+        self.current_attempt_duration() - loading_times
+    }
+
     /// Returns the total amount of time the current attempt has been paused
     /// for. None is returned if there have not been any pauses.
     pub fn get_pause_time(&self) -> Option<TimeSpan> {
-        let t0 = TimeStamp::now();
-        let t0_utc = AtomicDateTime::now();
+        let t0 = self.now();
+        let t0_utc = self.now_utc();
         let pt = match self.current_phase() {
             Paused => Some(t0 - self.start_time_with_offset - self.time_paused_at),
             Running | Ended if self.start_time_with_offset != self.adjusted_start_time => {
@@ -886,12 +2747,14 @@ impl Timer {
     #[inline]
     pub fn initialize_game_time(&mut self) {
         self.loading_times = Some(self.loading_times());
+        self.push_to_action_log(LoggedAction::InitializeGameTime);
     }
 
     /// Deinitializes Game Time for the current attempt.
     #[inline]
     pub fn deinitialize_game_time(&mut self) {
         self.loading_times = None;
+        self.push_to_action_log(LoggedAction::DeinitializeGameTime);
     }
 
     /// Returns whether the Game Timer is currently paused. If the Game Timer is
@@ -908,33 +2771,119 @@ impl Timer {
             let current_time = self.current_time();
             self.game_time_pause_time = current_time.game_time.or(current_time.real_time);
             self.is_game_time_paused = true;
+            self.game_time_report = None;
+            self.push_to_action_log(LoggedAction::PauseGameTime);
         }
     }
 
     /// Resumes the Game Timer such that it automatically increments similar to
     /// Real Time, starting from the Game Time it was paused at.
     pub fn resume_game_time(&mut self) {
+        if self.is_game_time_paused() {
+            self.unpause_game_time();
+            self.push_to_action_log(LoggedAction::ResumeGameTime);
+        }
+    }
+
+    fn unpause_game_time(&mut self) {
         if self.is_game_time_paused() {
             let current_time = self.current_time();
             let diff = catch! { current_time.real_time? - current_time.game_time? };
-            self.set_loading_times(diff.unwrap_or_default());
+            if matches!(self.phase, Running | Paused) {
+                self.apply_loading_times(diff.unwrap_or_default());
+            }
             self.is_game_time_paused = false;
         }
     }
 
+    /// Toggles the Game Timer between being paused and automatically
+    /// incrementing, by either pausing or resuming it.
+    pub fn toggle_game_time_pause(&mut self) {
+        if self.is_game_time_paused() {
+            self.resume_game_time();
+        } else {
+            self.pause_game_time();
+        }
+    }
+
     /// Sets the Game Time to the time specified. This also works if the Game
     /// Time is paused, which can be used as a way of updating the Game Timer
     /// periodically without it automatically moving forward. This ensures that
     /// the Game Timer never shows any time that is not coming from the game.
+    /// If there's no attempt in progress or the attempt already ended, nothing
+    /// happens.
     #[inline]
     pub fn set_game_time(&mut self, game_time: TimeSpan) {
+        if !matches!(self.phase, Running | Paused) {
+            return;
+        }
+        let real_time = self.current_time().real_time.unwrap();
+        self.set_game_time_at(game_time, real_time);
+    }
+
+    /// Sets the Game Time to the time specified, just like
+    /// [`Timer::set_game_time`]. However, instead of correlating the Game Time
+    /// with the current Real Time, the Real Time the Game Time sample
+    /// corresponds to is provided explicitly. Auto splitters that read the
+    /// Game Time with some latency, such as from a video frame, can use this to
+    /// calculate the loading times against the moment the sample got taken,
+    /// which avoids jitter in the Game Time caused by the sampling latency. If
+    /// there's no attempt in progress or the attempt already ended, nothing
+    /// happens.
+    pub fn set_game_time_at(&mut self, game_time: TimeSpan, real_time: TimeSpan) {
+        if !matches!(self.phase, Running | Paused) {
+            return;
+        }
         if self.is_game_time_paused() {
             self.game_time_pause_time = Some(game_time);
         }
-        self.loading_times = Some(self.current_time().real_time.unwrap() - game_time);
+        self.loading_times = Some(real_time - game_time);
+        self.push_to_action_log(LoggedAction::SetGameTime {
+            game_time,
+            real_time,
+        });
+
+        if self.game_time_smoothing {
+            let rate = self.game_time_report.and_then(|previous| {
+                let elapsed = (real_time - previous.real_time).total_seconds();
+                (elapsed > 0.0)
+                    .then(|| ((game_time - previous.game_time).total_seconds() / elapsed).max(0.0))
+            });
+            self.game_time_report = Some(GameTimeReport {
+                real_time,
+                game_time,
+                rate,
+            });
+        }
     }
 
-    /// Accesses the loading times. Loading times are defined as Game Time - Real Time.
+    /// Returns whether the paused Game Time gets extrapolated between the
+    /// updates provided via [`Timer::set_game_time`].
+    #[inline]
+    pub const fn is_game_time_smoothing(&self) -> bool {
+        self.game_time_smoothing
+    }
+
+    /// Enables or disables smoothing of the paused Game Time. When the Game
+    /// Time gets updated at irregular intervals, the Game Timer visibly jumps.
+    /// With smoothing enabled, the Game Time is extrapolated linearly between
+    /// the updates, based on the rate it progressed at between the last two
+    /// updates. Whenever a new Game Time is reported, the Game Timer snaps
+    /// exactly to it, so no drift is accumulated. This is off by default.
+    pub fn set_game_time_smoothing(&mut self, on: bool) {
+        self.game_time_smoothing = on;
+        self.game_time_report = None;
+    }
+
+    fn smoothed_game_time(&self, real_time: Option<TimeSpan>) -> Option<TimeSpan> {
+        let report = self.game_time_report?;
+        let elapsed = (real_time? - report.real_time).total_seconds();
+        Some(report.game_time + TimeSpan::from_seconds(elapsed * report.rate?))
+    }
+
+    /// Accesses the loading times. Loading times are defined as Real Time -
+    /// Game Time. They may be negative if the Game Time is ahead of the Real
+    /// Time.
     #[inline]
     pub fn loading_times(&self) -> TimeSpan {
         self.loading_times.unwrap_or_default()
@@ -942,13 +2891,37 @@ impl Timer {
 
     /// Instead of setting the Game Time directly, this method can be used to
     /// just specify the amount of time the game has been loading. The Game Time
-    /// is then automatically determined by Real Time - Loading Times.
+    /// is then automatically determined by Real Time - Loading Times. If
+    /// there's no attempt in progress or the attempt already ended, nothing
+    /// happens.
     #[inline]
     pub fn set_loading_times(&mut self, time: TimeSpan) {
+        if !matches!(self.phase, Running | Paused) {
+            return;
+        }
+        self.apply_loading_times(time);
+        self.push_to_action_log(LoggedAction::SetLoadingTimes(time));
+    }
+
+    fn apply_loading_times(&mut self, time: TimeSpan) {
         self.loading_times = Some(time);
         if self.is_game_time_paused() {
             self.game_time_pause_time = Some(self.current_time().real_time.unwrap() - time);
         }
+        self.game_time_report = None;
+    }
+
+    /// Resets the loading times to zero, so that the Game Time matches the Real
+    /// Time again and progresses along with it from now on. This can be used
+    /// when the load removal of an auto splitter has drifted. The Game Time
+    /// stays initialized. This is equivalent to calling
+    /// [`set_loading_times`](Timer::set_loading_times) with a time of zero, but
+    /// additionally notifies about the changed state.
+    pub fn sync_game_time_to_real_time(&mut self) {
+        if matches!(self.phase, Running | Paused) {
+            self.set_loading_times(TimeSpan::zero());
+            self.save_state(Action::None);
+        }
     }
 
     /// Sets the value of a custom variable with the name specified. If the
@@ -966,7 +2939,25 @@ impl Timer {
         }
     }
 
-    fn update_attempt_history(&mut self) {
+    /// Returns an iterator iterating over the names and values of all the
+    /// temporary custom variables. These are the custom variables that are
+    /// not stored in the splits file, such as the ones provided by an auto
+    /// splitter.
+    pub fn temporary_variables(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.run
+            .metadata()
+            .custom_variables()
+            .filter(|(_, var)| !var.is_permanent)
+            .map(|(name, var)| (name, var.value.as_str()))
+    }
+
+    /// Removes all the temporary custom variables. The permanent custom
+    /// variables stored in the splits file are not affected.
+    pub fn clear_temporary_variables(&mut self) {
+        self.run.metadata_mut().remove_temporary_custom_variables();
+    }
+
+    fn update_attempt_history(&mut self, loading_times: Option<TimeSpan>) {
         let time = if self.phase == Ended {
             self.current_time()
         } else {
@@ -977,42 +2968,33 @@ impl Timer {
 
         self.run
             .add_attempt(time, self.attempt_started, self.attempt_ended, pause_time);
+        if let Some(attempt) = self.run.attempt_history_mut().last_mut() {
+            attempt.set_metadata(mem::take(&mut self.current_attempt_metadata));
+            *attempt.pause_intervals_mut() = mem::take(&mut self.pause_intervals);
+            attempt.set_loading_times(loading_times);
+            attempt.set_comparison(Some(self.current_comparison.clone()));
+        }
     }
 
     fn update_best_segments(&mut self) {
-        let mut previous_split_time_rta = Some(TimeSpan::zero());
-        let mut previous_split_time_game_time = Some(TimeSpan::zero());
-
-        for split in self.run.segments_mut() {
-            let mut new_best_segment = split.best_segment_time();
-            if let Some(split_time) = split.split_time().real_time {
-                let current_segment = previous_split_time_rta.map(|previous| split_time - previous);
-                previous_split_time_rta = Some(split_time);
-                if split
-                    .best_segment_time()
-                    .real_time
-                    .map_or(true, |b| current_segment.map_or(false, |c| c < b))
-                {
-                    new_best_segment.real_time = current_segment;
+        for method in TimingMethod::all() {
+            let mut previous_split_time = Some(TimeSpan::zero());
+
+            for split in self.run.segments_mut() {
+                if let Some(split_time) = split.split_time()[method] {
+                    let current_segment = previous_split_time.map(|previous| split_time - previous);
+                    previous_split_time = Some(split_time);
+                    if split.best_segment_time()[method]
+                        .map_or(true, |b| current_segment.map_or(false, |c| c < b))
+                    {
+                        split.best_segment_time_mut()[method] = current_segment;
+                    }
                 }
             }
-            if let Some(split_time) = split.split_time().game_time {
-                let current_segment =
-                    previous_split_time_game_time.map(|previous| split_time - previous);
-                previous_split_time_game_time = Some(split_time);
-                if split
-                    .best_segment_time()
-                    .game_time
-                    .map_or(true, |b| current_segment.map_or(false, |c| c < b))
-                {
-                    new_best_segment.game_time = current_segment;
-                }
-            }
-            split.set_best_segment_time(new_best_segment);
         }
     }
 
-    fn update_pb_splits(&mut self) {
+    fn new_personal_best_state(&self) -> Option<TimerState> {
         let method = self.current_timing_method;
         let (split_time, pb_split_time) = {
             let last_segment = self.run.segments().last().unwrap();
@@ -1022,7 +3004,10 @@ impl Timer {
             )
         };
         if split_time.map_or(false, |s| pb_split_time.map_or(true, |pb| s < pb)) {
-            self.set_run_as_pb();
+            let improvement = catch! { pb_split_time? - split_time? };
+            Some(self.timer_state_with_improvement(Action::NewPersonalBest, improvement))
+        } else {
+            None
         }
     }
 
@@ -0,0 +1,29 @@
+use crate::{platform::prelude::*, Time, TimeSpan};
+
+/// An observation bundles the information about the Timer that is most
+/// commonly needed for visualizing it. Everything is calculated from a single
+/// [`Snapshot`](super::Snapshot), so all the values are consistent with each
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation {
+    /// The time the Timer was at when it was observed.
+    pub current_time: Time,
+    /// The duration of the current segment for the current timing method. If
+    /// the attempt is finished, this is the duration of the last segment. If
+    /// there's no attempt in progress, this is `None`.
+    pub current_segment_time: Option<TimeSpan>,
+    /// The delta of the current attempt to the current comparison for the
+    /// current timing method.
+    pub delta: Option<TimeSpan>,
+    /// Specifies whether the delta is a live delta that is actively changing,
+    /// because the attempt is slower than the comparison at the current split.
+    pub is_live_delta: bool,
+    /// The current comparison's split time of the current split for the
+    /// current timing method. If a transient comparison is installed, its
+    /// split time is used instead.
+    pub comparison_time: Option<TimeSpan>,
+    /// The name of the split the attempt is currently on.
+    pub current_split_name: Option<String>,
+    /// The name of the split that follows the current split.
+    pub next_split_name: Option<String>,
+}
@@ -0,0 +1,134 @@
+use super::{Action, CreationError, ResetKind, Timer};
+use crate::{AtomicDateTime, Run, TimeSpan, TimeStamp};
+
+/// An entry of the action log. Besides the actions performed on the Timer, the
+/// changes to the Game Time are recorded as well, so that replaying the log
+/// reproduces both the Real Time and the Game Time of the attempts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoggedAction {
+    /// An action got performed on the Timer. Resets and starts on a later
+    /// split are logged as [`LoggedAction::Reset`] and
+    /// [`LoggedAction::StartFromSplit`] instead.
+    Action(Action),
+    /// The attempt got reset via [`Timer::reset_with`] or any of the methods
+    /// based on it. This is the kind of reset that actually got applied, so
+    /// practice resets are logged as [`ResetKind::Discard`].
+    Reset(ResetKind),
+    /// An attempt got started on the split with the index provided via
+    /// [`Timer::start_from_split`].
+    StartFromSplit(usize),
+    /// The Game Time got initialized via [`Timer::initialize_game_time`].
+    InitializeGameTime,
+    /// The Game Time got deinitialized via [`Timer::deinitialize_game_time`].
+    DeinitializeGameTime,
+    /// The Game Timer got paused via [`Timer::pause_game_time`].
+    PauseGameTime,
+    /// The Game Timer got resumed via [`Timer::resume_game_time`].
+    ResumeGameTime,
+    /// The Game Time got set via [`Timer::set_game_time`] or
+    /// [`Timer::set_game_time_at`].
+    SetGameTime {
+        /// The Game Time that got set.
+        game_time: TimeSpan,
+        /// The Real Time the Game Time corresponds to.
+        real_time: TimeSpan,
+    },
+    /// The loading times got set via [`Timer::set_loading_times`].
+    SetLoadingTimes(TimeSpan),
+}
+
+impl Timer {
+    /// Creates a new Timer for the Run provided and replays the actions
+    /// provided on it, such as the ones recorded by the action log. Each action
+    /// is applied at the same offset from the start of its attempt as it got
+    /// recorded at, so the attempt is reproduced with the same split times as
+    /// the original one, except for the tiny delay between performing an
+    /// action and it being logged. As the offsets don't depend on the process
+    /// that recorded them, the log can be replayed anywhere. Resets are
+    /// replayed with the kind of reset they got recorded with and
+    /// [`Action::NewPersonalBest`] is skipped, as it's a consequence of the
+    /// reset before it. The changes to the Game Time are replayed as well.
+    /// The last action is considered to have happened just now, so once all
+    /// the actions are applied, an attempt that is still in progress
+    /// continues on the regular clock from there.
+    pub fn replay(actions: &[(TimeSpan, LoggedAction)], run: Run) -> Result<Timer, CreationError> {
+        let mut timer = Timer::new(run)?;
+        let (now, now_utc) = (TimeStamp::now(), AtomicDateTime::now());
+
+        // Every start begins a new attempt, which the offsets are relative to.
+        let is_start = |action: LoggedAction| {
+            matches!(
+                action,
+                LoggedAction::Action(Action::Start) | LoggedAction::StartFromSplit(_)
+            )
+        };
+        let mut attempts = Vec::new();
+        let mut remaining = actions;
+        while !remaining.is_empty() {
+            let len = remaining[1..]
+                .iter()
+                .position(|&(_, action)| is_start(action))
+                .map_or(remaining.len(), |index| index + 1);
+            let (attempt, rest) = remaining.split_at(len);
+            attempts.push(attempt);
+            remaining = rest;
+        }
+
+        // Each attempt is placed right before the next one, going backwards
+        // from now. The time in between attempts doesn't affect any of them.
+        let mut end = now;
+        let mut ends = attempts
+            .iter()
+            .rev()
+            .map(|attempt| {
+                let attempt_end = end;
+                end = end - attempt.last().unwrap().0;
+                attempt_end
+            })
+            .collect::<Vec<_>>();
+        ends.reverse();
+
+        for (attempt, end) in attempts.into_iter().zip(ends) {
+            let last_offset = attempt.last().unwrap().0;
+            for &(offset, action) in attempt {
+                let time = end - (last_offset - offset);
+                timer.replay_clock = Some((time, now_utc - (now - time)));
+                timer.apply_logged_action(action);
+            }
+        }
+
+        timer.replay_clock = None;
+        Ok(timer)
+    }
+
+    fn apply_logged_action(&mut self, action: LoggedAction) {
+        match action {
+            LoggedAction::Action(action) => match action {
+                Action::Start => self.start(),
+                Action::Split => {
+                    self.split();
+                }
+                Action::Skip => self.skip_split(),
+                Action::Undo => self.undo_split(),
+                Action::Pause => self.pause(),
+                Action::Resume => self.resume(),
+                Action::Reset | Action::None | Action::NewPersonalBest => {}
+            },
+            LoggedAction::Reset(kind) => {
+                self.reset_with(kind);
+            }
+            LoggedAction::StartFromSplit(index) => {
+                let _ = self.start_from_split(index);
+            }
+            LoggedAction::InitializeGameTime => self.initialize_game_time(),
+            LoggedAction::DeinitializeGameTime => self.deinitialize_game_time(),
+            LoggedAction::PauseGameTime => self.pause_game_time(),
+            LoggedAction::ResumeGameTime => self.resume_game_time(),
+            LoggedAction::SetGameTime {
+                game_time,
+                real_time,
+            } => self.set_game_time_at(game_time, real_time),
+            LoggedAction::SetLoadingTimes(time) => self.set_loading_times(time),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+/// Describes how much of an attempt gets stored when it is reset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResetKind {
+    /// All the information of the attempt is discarded.
+    Discard,
+    /// The attempt is stored in the Attempt History and its segment times are
+    /// stored in the Segment History, but the Best Segments and the Personal
+    /// Best are left untouched. As the Segment History can't contain times
+    /// faster than the Best Segments, such segment times are stored in the
+    /// Segment History as the Best Segment times instead. For the same reason
+    /// segments without a Best Segment time don't keep their segment times in
+    /// the Segment History.
+    SaveHistoryOnly,
+    /// All the information of the attempt is stored. This updates the
+    /// histories, the Best Segments and possibly the Personal Best.
+    SaveAll,
+}
+
+impl From<bool> for ResetKind {
+    fn from(update_splits: bool) -> Self {
+        if update_splits {
+            ResetKind::SaveAll
+        } else {
+            ResetKind::Discard
+        }
+    }
+}
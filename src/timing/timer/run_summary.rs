@@ -0,0 +1,84 @@
+use super::Timer;
+use crate::{
+    analysis::sum_of_segments::calculate_best, platform::prelude::*, TimerPhase, TimingMethod,
+};
+use serde::{Deserialize, Serialize};
+
+/// A summary of the current attempt that bundles everything that is usually
+/// shared when posting a result, such as to a leaderboard. All the times are
+/// stored in seconds for the timing method the summary got created for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// The name of the game the Run is for.
+    pub game_name: String,
+    /// The name of the category the Run is for.
+    pub category_name: String,
+    /// The final time of the attempt. This is only available if the attempt
+    /// is finished.
+    pub final_time: Option<f64>,
+    /// The name of the comparison the deltas are based on.
+    pub comparison: String,
+    /// The summary of each segment of the attempt.
+    pub segments: Vec<SegmentSummary>,
+    /// The Sum of Best Segments.
+    pub sum_of_best: Option<f64>,
+    /// The amount of attempts that have been started on the Run.
+    pub attempt_count: u32,
+}
+
+/// The summary of a single segment of an attempt as part of a [`RunSummary`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentSummary {
+    /// The name of the segment.
+    pub name: String,
+    /// The split time of the segment. This is `None` if the segment got
+    /// skipped or wasn't split yet.
+    pub split_time: Option<f64>,
+    /// The difference between the split time and the comparison's split time
+    /// of the segment. Negative values mean that the comparison was beaten.
+    pub delta: Option<f64>,
+}
+
+impl Timer {
+    /// Creates a summary of the current attempt for the timing method
+    /// provided, bundling the names of the game and the category, the final
+    /// time, the split times and deltas of each segment compared to the
+    /// current comparison, the Sum of Best Segments and the attempt count.
+    pub fn run_summary(&self, method: TimingMethod) -> RunSummary {
+        let run = &self.run;
+        let comparison = self.current_comparison();
+
+        let segments = run
+            .segments()
+            .iter()
+            .map(|segment| {
+                let split_time = segment.split_time()[method];
+                SegmentSummary {
+                    name: segment.name().to_owned(),
+                    split_time: split_time.map(f64::from),
+                    delta: catch! { split_time? - segment.comparison(comparison)[method]? }
+                        .map(f64::from),
+                }
+            })
+            .collect();
+
+        let final_time = if self.current_phase() == TimerPhase::Ended {
+            run.segments()
+                .last()
+                .and_then(|s| s.split_time()[method])
+                .map(f64::from)
+        } else {
+            None
+        };
+
+        RunSummary {
+            game_name: run.game_name().to_owned(),
+            category_name: run.category_name().to_owned(),
+            final_time,
+            comparison: comparison.to_owned(),
+            segments,
+            sum_of_best: calculate_best(run.segments(), false, true, method).map(f64::from),
+            attempt_count: run.attempt_count(),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use super::{CreationError, StateIssue, Timer, TimerState};
+use crate::{
+    platform::prelude::*,
+    run::{parser::livesplit, saver},
+};
+use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
+
+/// The Error type for sessions that couldn't be saved or loaded.
+#[derive(Debug, snafu::Snafu)]
+pub enum SessionError {
+    /// The session couldn't be encoded or decoded as JSON.
+    Json {
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// The splits stored in the session couldn't be parsed.
+    Run {
+        /// The underlying error.
+        source: livesplit::Error,
+    },
+    /// The splits stored in the session can't be used for timing.
+    Timer {
+        /// The underlying error.
+        source: CreationError,
+    },
+    /// The Timer State stored in the session couldn't be restored, such as
+    /// when it doesn't have the same amount of splits as the splits stored
+    /// alongside it have segments.
+    InvalidState {
+        /// The issue with the Timer State.
+        issue: StateIssue,
+    },
+}
+
+/// A session consists of the splits in the LiveSplit splits format and the
+/// state of the attempt in progress.
+#[derive(Serialize, Deserialize)]
+struct Session {
+    run: String,
+    state: TimerState,
+}
+
+impl Timer {
+    /// Saves the whole session, consisting of the Run and the state of the
+    /// attempt in progress, so that it can be fully restored via
+    /// [`Timer::load_session`], such as after a crash. Unlike the
+    /// [`TimerState`], this includes everything stored in the splits.
+    pub fn save_session(&self) -> Result<Vec<u8>, SessionError> {
+        let mut run = String::new();
+        // Writing into a String can't fail.
+        let _ = saver::livesplit::save_run(&self.run, &mut run);
+        // The state is not rounded to the state precision, so that the attempt
+        // is restored with exactly the same times.
+        serde_json::to_vec(&Session {
+            run,
+            state: self.into(),
+        })
+        .context(JsonSnafu)
+    }
+
+    /// Loads a session that was previously saved via [`Timer::save_session`].
+    /// The Timer is created from the Run stored in the session and the
+    /// attempt that was in progress is restored.
+    pub fn load_session(data: &[u8]) -> Result<Timer, SessionError> {
+        let session: Session = serde_json::from_slice(data).context(JsonSnafu)?;
+        let run = livesplit::parse(&session.run).context(RunSnafu)?;
+        let mut timer = Timer::new(run).context(TimerSnafu)?;
+        timer
+            .replace_state(&session.state)
+            .map_err(|issue| SessionError::InvalidState { issue })?;
+        Ok(timer)
+    }
+}
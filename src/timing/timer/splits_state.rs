@@ -0,0 +1,24 @@
+use super::{Snapshot, Timer};
+use crate::{
+    component::splits::{self, Settings as SplitsSettings},
+    layout::GeneralSettings as GeneralLayoutSettings,
+};
+
+impl Timer {
+    /// Builds the state of a [`Splits Component`](splits::Component) with the
+    /// settings provided, such as its columns and the amount of segments it
+    /// shows. Everything is assembled from the snapshot provided, which needs
+    /// to be a snapshot of this Timer, so the split times, the comparison
+    /// times and the deltas are all consistent with each other. The scrolling
+    /// window follows the current split, just like it does for a freshly
+    /// created Splits Component.
+    pub fn build_splits_state(
+        &self,
+        snapshot: &Snapshot<'_>,
+        settings: &SplitsSettings,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> splits::State {
+        debug_assert!(core::ptr::eq(snapshot.timer, self));
+        splits::Component::with_settings(settings.clone()).state(snapshot, layout_settings)
+    }
+}
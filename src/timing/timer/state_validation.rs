@@ -0,0 +1,149 @@
+use super::{Time64, Timer, TimerState, ADT};
+use crate::{platform::prelude::*, AtomicDateTime, TimerPhase};
+
+/// Describes a problem with a [`TimerState`] that prevents it from being used
+/// for restoring the state of a [`Timer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateIssue {
+    /// The state doesn't have the same amount of splits as the Run has
+    /// segments.
+    SegmentCountMismatch {
+        /// The amount of segments of the Run.
+        expected: usize,
+        /// The amount of splits in the state.
+        actual: usize,
+    },
+    /// The Timer Phase of the state is not known.
+    UnknownPhase {
+        /// The Timer Phase as stored in the state.
+        phase: String,
+    },
+    /// The index of the current split doesn't fit the Timer Phase and the
+    /// amount of segments.
+    InvalidSplitIndex {
+        /// The index of the current split as stored in the state.
+        index: Option<usize>,
+    },
+    /// A time stored in the state is not a finite number.
+    NonFiniteTime {
+        /// The name of the field that contains the time.
+        field: &'static str,
+    },
+    /// A timestamp stored in the state couldn't be parsed.
+    InvalidTimestamp {
+        /// The name of the field that contains the timestamp.
+        field: &'static str,
+    },
+}
+
+fn parse_phase(phase: &str) -> Option<TimerPhase> {
+    Some(match phase {
+        "NotRunning" => TimerPhase::NotRunning,
+        "Running" => TimerPhase::Running,
+        "Ended" => TimerPhase::Ended,
+        "Paused" => TimerPhase::Paused,
+        _ => return None,
+    })
+}
+
+impl Timer {
+    /// Checks whether the state provided can be used for restoring the state
+    /// of this Timer via [`Timer::replace_state`] without modifying anything.
+    /// Instead of stopping at the first problem, all the problems with the
+    /// state are collected and returned.
+    pub fn validate_state(&self, state: &TimerState) -> Result<(), Vec<StateIssue>> {
+        let mut issues = Vec::new();
+
+        let len = self.run.len();
+        if state.splits.len() != len {
+            issues.push(StateIssue::SegmentCountMismatch {
+                expected: len,
+                actual: state.splits.len(),
+            });
+        }
+
+        match parse_phase(&state.phase) {
+            Some(phase) => {
+                let index = state.current_split_index;
+                let is_valid = match phase {
+                    TimerPhase::NotRunning => index.is_none(),
+                    TimerPhase::Running | TimerPhase::Paused => index.map_or(false, |i| i < len),
+                    TimerPhase::Ended => index == Some(len),
+                };
+                if !is_valid {
+                    issues.push(StateIssue::InvalidSplitIndex { index });
+                }
+            }
+            None => issues.push(StateIssue::UnknownPhase {
+                phase: state.phase.clone(),
+            }),
+        }
+
+        let mut check_time = |field, time: Option<f64>| {
+            if time.map_or(false, |t| !t.is_finite()) {
+                issues.push(StateIssue::NonFiniteTime { field });
+            }
+        };
+        for &Time64 {
+            real_time,
+            game_time,
+        } in &state.splits
+        {
+            check_time("splits", real_time);
+            check_time("splits", game_time);
+        }
+        check_time("time_paused_at", Some(state.time_paused_at));
+        check_time("game_time_pause_time", state.game_time_pause_time);
+        check_time("loading_times", state.loading_times);
+
+        let mut check_timestamp = |field, timestamp: Option<&ADT>| {
+            if timestamp.map_or(false, |t| AtomicDateTime::try_from(t).is_err()) {
+                issues.push(StateIssue::InvalidTimestamp { field });
+            }
+        };
+        check_timestamp("attempt_started", state.attempt_started.as_ref());
+        check_timestamp("attempt_ended", state.attempt_ended.as_ref());
+        check_timestamp("start_time_utc", Some(&state.start_time_utc));
+        check_timestamp(
+            "start_time_with_offset_utc",
+            Some(&state.start_time_with_offset_utc),
+        );
+        check_timestamp(
+            "adjusted_start_time_utc",
+            Some(&state.adjusted_start_time_utc),
+        );
+        for interval in &state.pause_intervals {
+            check_timestamp("pause_intervals", Some(&interval.started));
+            check_timestamp("pause_intervals", interval.ended.as_ref());
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Repairs the index of the current split if it doesn't fit the current
+    /// Timer Phase, which may happen when restoring a malformed state via
+    /// [`Timer::replace_state`]. The index gets clamped to the range that is
+    /// valid for the Timer Phase, so that splitting doesn't panic. Returns the
+    /// issue that got repaired, if there was any.
+    pub fn repair_state(&mut self) -> Option<StateIssue> {
+        let len = self.run.len();
+        let index = self.current_split_index;
+        let repaired = match self.phase {
+            TimerPhase::NotRunning => None,
+            TimerPhase::Running | TimerPhase::Paused => {
+                Some(index.unwrap_or_default().min(len - 1))
+            }
+            TimerPhase::Ended => Some(len),
+        };
+        if repaired == index {
+            return None;
+        }
+        self.current_split_index = repaired;
+        self.debug_assert_split_index();
+        Some(StateIssue::InvalidSplitIndex { index })
+    }
+}
@@ -0,0 +1,31 @@
+use super::Timer;
+use crate::{Time, TimerPhase, TimingMethod};
+
+/// A lightweight summary of the Timer's status that can be polled very
+/// frequently, such as on every frame. Unlike the [`TimerState`](super::TimerState),
+/// it doesn't allocate and can be freely copied out of a locked Timer.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TimerStatus {
+    /// The Timer Phase the Timer is in.
+    pub phase: TimerPhase,
+    /// The index of the current split. This is `None` if there's no attempt
+    /// in progress.
+    pub current_split_index: Option<usize>,
+    /// The current time of the Timer.
+    pub current_time: Time,
+    /// The Timing Method that is currently selected.
+    pub current_timing_method: TimingMethod,
+}
+
+impl Timer {
+    /// Returns the current status of the Timer. This is a cheap alternative to
+    /// [`Timer::timer_state`] for polling the Timer frequently.
+    pub fn status(&self) -> TimerStatus {
+        TimerStatus {
+            phase: self.phase,
+            current_split_index: self.current_split_index,
+            current_time: self.current_time(),
+            current_timing_method: self.current_timing_method,
+        }
+    }
+}
@@ -1,7 +1,18 @@
+use super::Action;
 use crate::{
+    analysis,
+    comparison::personal_best,
     run::Editor,
-    util::tests_helper::{run_with_splits, run_with_splits_opt, start_run},
-    Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
+    timing::{
+        AvailableActions, DeltaCategory, LoggedAction, ReplaceRunError, ResetKind,
+        SetSplitTimesError, StartFromSplitError, StateIssue, TimerComparisonError,
+        TimerCreationError, TimerSessionError,
+    },
+    util::tests_helper::{
+        make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, start_run,
+    },
+    AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp, Timer, TimerPhase, TimerState,
+    TimingMethod,
 };
 
 mod mark_as_modified;
@@ -619,3 +630,2650 @@ fn reset_and_set_attempt_as_pb() {
     assert_eq!(attempt.time().game_time, None);
     assert!(attempt.ended().unwrap().time >= attempt.started().unwrap().time);
 }
+
+#[test]
+fn creation_validates_the_run_when_requested() {
+    let build = |run: Run| Timer::builder(run).validate(true).build();
+
+    assert!(matches!(
+        build(Run::new()),
+        Err(TimerCreationError::EmptyRun)
+    ));
+
+    assert!(build(run()).is_ok());
+
+    let mut duplicate = run();
+    duplicate.custom_comparisons_mut().push("Foo".into());
+    duplicate.custom_comparisons_mut().push("Foo".into());
+    assert!(matches!(
+        build(duplicate.clone()),
+        Err(TimerCreationError::DuplicateComparison { name }) if name == "Foo"
+    ));
+    assert!(Timer::new(duplicate).is_ok());
+
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    let mut run = timer.into_run(true);
+
+    run.set_offset(TimeSpan::from_seconds(2.0));
+    assert!(build(run.clone()).is_ok());
+
+    run.set_offset(TimeSpan::from_seconds(3.0));
+    assert!(matches!(
+        Timer::validate_run(&run),
+        Err(TimerCreationError::InvalidOffset)
+    ));
+    assert!(matches!(
+        build(run.clone()),
+        Err(TimerCreationError::InvalidOffset)
+    ));
+    assert!(Timer::new(run).is_ok());
+}
+
+#[test]
+fn into_run_preserving_keeps_the_attempt() {
+    let (_, state) = timer().into_run_preserving();
+    assert!(state.is_none());
+
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    timer.split();
+
+    let (run, state) = timer.into_run_preserving();
+    let state = state.unwrap();
+    assert_eq!(state.current_split_index, Some(1));
+    assert_eq!(
+        run.segment(0).split_time().game_time,
+        Some(TimeSpan::from_seconds(5.0))
+    );
+    assert_eq!(run.attempt_history().len(), 0);
+
+    let mut timer = Timer::new(run).unwrap();
+    timer.replace_state(&state).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+}
+
+#[test]
+fn replace_run_keeping_attempt() {
+    let mut timer = timer();
+    assert!(matches!(
+        timer.replace_run_keeping_attempt(run()),
+        Err(ReplaceRunError::NoAttemptInProgress { .. })
+    ));
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    timer.split();
+
+    let mut two_segments = Run::new();
+    two_segments.push_segment(Segment::new("A"));
+    two_segments.push_segment(Segment::new("B"));
+    assert_eq!(
+        timer
+            .replace_run_keeping_attempt(two_segments)
+            .unwrap_err()
+            .into_run()
+            .len(),
+        2
+    );
+
+    let split_utc = timer.split_datetime_utc(0);
+    assert!(split_utc.is_some());
+
+    let mut new_run = run();
+    new_run.segment_mut(1).set_name("New B");
+    let old_run = timer.replace_run_keeping_attempt(new_run).unwrap();
+    assert_eq!(old_run.segment(1).name(), "B");
+    assert!(timer.run().has_been_modified());
+    assert_eq!(timer.split_datetime_utc(0), split_utc);
+
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split().unwrap().name(), "New B");
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(TimeSpan::from_seconds(5.0))
+    );
+}
+
+#[test]
+fn segment_history() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 3.0, 5.0]);
+
+    let history = timer
+        .segment_history(2)
+        .map(|(index, time)| (index, time.game_time))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        history,
+        [
+            (1, Some(TimeSpan::from_seconds(3.0))),
+            (2, Some(TimeSpan::from_seconds(2.0))),
+        ]
+    );
+
+    assert_eq!(timer.segment_history(3).count(), 0);
+}
+
+#[test]
+fn segment_median_and_mean() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 3.0, 8.0]);
+    run_with_splits_opt(&mut timer, &[Some(1.5), None, Some(3.0)]);
+    run_with_splits(&mut timer, &[1.0, 2.0, 6.0]);
+
+    // The skipped segment doesn't count towards the second segment.
+    assert_eq!(
+        timer.segment_median(1, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.0))
+    );
+    assert_eq!(
+        timer.segment_mean(1, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(4.0 / 3.0))
+    );
+
+    assert_eq!(
+        timer.segment_median(0, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.25))
+    );
+    assert_eq!(
+        timer.segment_mean(0, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.375))
+    );
+
+    assert_eq!(timer.segment_median(3, TimingMethod::GameTime), None);
+    assert_eq!(timer.segment_mean(3, TimingMethod::GameTime), None);
+}
+
+#[test]
+fn segment_median_and_mean_exclude_imported_best_segments() {
+    let mut run = run();
+    let imported = Time::new().with_game_time(Some(TimeSpan::from_seconds(0.5)));
+    run.segment_mut(0).set_best_segment_time(imported);
+    run.segment_mut(0).segment_history_mut().insert(0, imported);
+    let mut timer = Timer::new(run).unwrap();
+    assert_eq!(timer.segment_history(0).count(), 1);
+    assert_eq!(timer.segment_median(0, TimingMethod::GameTime), None);
+    assert_eq!(timer.segment_mean(0, TimingMethod::GameTime), None);
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 3.0, 8.0]);
+    assert_eq!(
+        timer.segment_median(0, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.5))
+    );
+    assert_eq!(
+        timer.segment_mean(0, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.5))
+    );
+}
+
+#[test]
+fn segment_std_dev() {
+    // Imported best segments are not based on actual attempts.
+    let mut run = run();
+    let imported = Time::new().with_game_time(Some(TimeSpan::from_seconds(0.5)));
+    run.segment_mut(0).set_best_segment_time(imported);
+    run.segment_mut(0).segment_history_mut().insert(0, imported);
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    assert_eq!(timer.segment_std_dev(0, TimingMethod::GameTime), None);
+
+    run_with_splits(&mut timer, &[3.0, 4.0, 8.0]);
+    run_with_splits_opt(&mut timer, &[Some(2.0), None, Some(3.0)]);
+
+    assert_eq!(
+        timer.segment_std_dev(0, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.0))
+    );
+    // The skipped segment doesn't count towards the second segment.
+    assert_eq!(
+        timer.segment_std_dev(1, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(0.5f64.sqrt()))
+    );
+    assert_eq!(timer.segment_std_dev(3, TimingMethod::GameTime), None);
+}
+
+#[test]
+fn sum_of_best_from_history() {
+    // Imported best segments are not based on actual attempts.
+    let mut run = run();
+    let imported = Time::new().with_game_time(Some(TimeSpan::from_seconds(0.5)));
+    run.segment_mut(0).set_best_segment_time(imported);
+    run.segment_mut(0).segment_history_mut().insert(0, imported);
+    let mut timer = Timer::new(run).unwrap();
+    assert_eq!(timer.sum_of_best_from_history(TimingMethod::GameTime), None);
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 3.0, 5.0]);
+
+    assert_eq!(
+        timer.sum_of_best_from_history(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(4.0))
+    );
+}
+
+#[test]
+fn game_time_offset() {
+    let mut timer = timer();
+    timer.use_utc(false);
+    timer.start();
+
+    let snapshot = timer.snapshot();
+    assert_eq!(timer.game_time_offset(&snapshot), None);
+
+    timer.initialize_game_time();
+    timer.set_loading_times(TimeSpan::from_seconds(2.0));
+    let snapshot = timer.snapshot();
+    assert_eq!(
+        timer.game_time_offset(&snapshot),
+        Some(TimeSpan::from_seconds(-2.0))
+    );
+}
+
+#[test]
+fn setting_game_time_after_the_attempt_ended_without_real_time() {
+    let mut timer = Timer::new(run()).unwrap();
+    start_run(&mut timer);
+    timer.split();
+    timer.split();
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    let mut state = timer.timer_state(super::Action::None);
+    for split in &mut state.splits {
+        split.real_time = None;
+    }
+    timer.replace_state(&state).unwrap();
+
+    // Autosplitters may still send Game Time updates right as the attempt
+    // ends. These used to panic, as there's no Real Time to base them on.
+    let loading_times = timer.loading_times();
+    timer.set_game_time(TimeSpan::from_seconds(1.0));
+    timer.set_loading_times(TimeSpan::from_seconds(1.0));
+    assert_eq!(timer.loading_times(), loading_times);
+
+    timer.reset(true);
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+}
+
+#[test]
+fn start_from_split() {
+    let mut timer = timer();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    // There's no Personal Best to base the start time on yet.
+    assert!(matches!(
+        timer.start_from_split(1),
+        Err(StartFromSplitError::NoPersonalBestSplitTime)
+    ));
+    assert!(matches!(
+        timer.start_from_split(3),
+        Err(StartFromSplitError::OutOfRange)
+    ));
+
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    timer.set_current_timing_method(TimingMethod::RealTime);
+    let pb_split_time = timer.run().segment(0).personal_best_split_time().real_time;
+
+    timer.start_from_split(1).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert!(timer.snapshot().current_time().real_time >= pb_split_time);
+
+    // Only valid if there's no attempt in progress.
+    assert!(matches!(
+        timer.start_from_split(0),
+        Err(StartFromSplitError::AttemptInProgress)
+    ));
+}
+
+#[test]
+fn attempts_started_from_split_are_discarded() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    let attempts = timer.run().attempt_history().len();
+    let best_segment = timer.run().segment(1).best_segment_time();
+    let personal_best = timer.run().segment(2).personal_best_split_time();
+
+    timer.set_current_timing_method(TimingMethod::RealTime);
+    timer.start_from_split(1).unwrap();
+    assert!(timer.is_practice_attempt());
+    timer.split();
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    timer.reset(true);
+
+    assert!(!timer.is_practice_attempt());
+    assert_eq!(timer.run().attempt_history().len(), attempts);
+    assert_eq!(timer.run().segment(1).best_segment_time(), best_segment);
+    assert_eq!(
+        timer.run().segment(2).personal_best_split_time(),
+        personal_best
+    );
+}
+
+#[test]
+fn negative_loading_times() {
+    let mut timer = timer();
+    timer.use_utc(false);
+    timer.start();
+    timer.initialize_game_time();
+
+    timer.set_loading_times(TimeSpan::from_seconds(-5.0));
+    let time = timer.snapshot().current_time();
+    assert_eq!(
+        catch! { time.game_time? - time.real_time? },
+        Some(TimeSpan::from_seconds(5.0))
+    );
+
+    // Setting the Game Time ahead of the Real Time results in negative loading
+    // times.
+    timer.pause_game_time();
+    timer.set_game_time(TimeSpan::from_seconds(1000.0));
+    assert!(timer.loading_times() < TimeSpan::zero());
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(1000.0))
+    );
+
+    // After resuming, the Game Time keeps on running ahead of the Real Time.
+    timer.resume_game_time();
+    let time = timer.snapshot().current_time();
+    assert!(time.game_time.unwrap() >= TimeSpan::from_seconds(1000.0));
+    assert!(time.game_time > time.real_time);
+    assert_eq!(
+        catch! { time.real_time? - time.game_time? },
+        Some(timer.loading_times())
+    );
+}
+
+#[test]
+fn observe() {
+    let mut timer = timer();
+    let observation = timer.observe();
+    assert_eq!(observation.current_segment_time, None);
+    assert_eq!(observation.delta, None);
+    assert_eq!(observation.current_split_name, None);
+    assert_eq!(observation.next_split_name, None);
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.5));
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+
+    let observation = timer.observe();
+    assert_eq!(
+        observation.current_time.game_time,
+        Some(TimeSpan::from_seconds(2.0))
+    );
+    assert_eq!(
+        observation.current_segment_time,
+        Some(TimeSpan::from_seconds(0.5))
+    );
+    assert_eq!(observation.delta, Some(TimeSpan::from_seconds(0.5)));
+    assert_eq!(
+        observation.comparison_time,
+        Some(TimeSpan::from_seconds(2.0))
+    );
+    assert_eq!(observation.current_split_name.as_deref(), Some("B"));
+    assert_eq!(observation.next_split_name.as_deref(), Some("C"));
+}
+
+#[test]
+fn transient_comparison() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    let custom_comparisons = timer.run().custom_comparisons().to_vec();
+
+    timer.set_transient_comparison(vec![
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(2.0))),
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(2.5))),
+    ]);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.5));
+    timer.split();
+
+    assert_eq!(
+        analysis::delta::calculate(&timer.snapshot(), timer.current_comparison()),
+        (Some(TimeSpan::from_seconds(-0.5)), false)
+    );
+    assert_eq!(
+        timer.comparison_time(1).game_time,
+        Some(TimeSpan::from_seconds(2.5))
+    );
+    // Segments without a transient split time have no comparison time.
+    assert_eq!(timer.comparison_time(2), Time::new());
+
+    timer.clear_transient_comparison();
+    assert_eq!(timer.transient_comparison(), None);
+    assert_eq!(
+        analysis::delta::calculate(&timer.snapshot(), timer.current_comparison()),
+        (Some(TimeSpan::from_seconds(0.5)), false)
+    );
+
+    // The transient comparison is never stored in the Run.
+    timer.reset(true);
+    assert_eq!(timer.run().custom_comparisons(), custom_comparisons);
+}
+
+#[test]
+fn skip_and_undo_at_the_last_split_keep_the_index_in_range() {
+    let mut timer = timer();
+    timer.start();
+
+    timer.skip_split();
+    timer.skip_split();
+    assert_eq!(timer.current_split_index(), Some(2));
+
+    // Skipping the last split is not possible.
+    timer.skip_split();
+    assert_eq!(timer.current_split_index(), Some(2));
+    assert_eq!(timer.current_split().unwrap().name(), "C");
+
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.current_split_index(), Some(3));
+    assert!(timer.current_split().is_none());
+
+    // Skipping after the attempt ended does nothing.
+    timer.skip_split();
+    assert_eq!(timer.current_split_index(), Some(3));
+
+    timer.undo_split();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(2));
+    assert_eq!(timer.current_split().unwrap().name(), "C");
+
+    timer.skip_split();
+    assert_eq!(timer.current_split_index(), Some(2));
+
+    timer.undo_split();
+    timer.undo_split();
+    assert_eq!(timer.current_split_index(), Some(0));
+    assert_eq!(timer.current_split().unwrap().name(), "A");
+
+    // Undoing the first split is not possible.
+    timer.undo_split();
+    assert_eq!(timer.current_split_index(), Some(0));
+    assert!(timer.current_split().is_some());
+}
+
+#[test]
+fn skip_and_undo_while_paused_keep_the_index_in_range() {
+    let mut timer = timer();
+    timer.start();
+    timer.pause();
+
+    for _ in 0..5 {
+        timer.skip_split();
+        assert!(timer.current_split().is_some());
+    }
+    assert_eq!(timer.current_split_index(), Some(2));
+
+    for _ in 0..5 {
+        timer.undo_split();
+        assert!(timer.current_split().is_some());
+    }
+    assert_eq!(timer.current_split_index(), Some(0));
+    assert_eq!(timer.current_phase(), TimerPhase::Paused);
+}
+
+#[test]
+fn available_actions() {
+    let mut timer = timer();
+    assert_eq!(
+        timer.available_actions(),
+        AvailableActions {
+            start: true,
+            ..Default::default()
+        }
+    );
+
+    timer.start();
+    assert_eq!(
+        timer.available_actions(),
+        AvailableActions {
+            split: true,
+            skip: true,
+            reset: true,
+            pause: true,
+            ..Default::default()
+        }
+    );
+
+    timer.pause();
+    assert_eq!(
+        timer.available_actions(),
+        AvailableActions {
+            skip: true,
+            reset: true,
+            resume: true,
+            ..Default::default()
+        }
+    );
+
+    timer.resume();
+    timer.split();
+    timer.split();
+    assert_eq!(
+        timer.available_actions(),
+        AvailableActions {
+            split: true,
+            undo: true,
+            reset: true,
+            pause: true,
+            ..Default::default()
+        }
+    );
+
+    timer.split();
+    assert_eq!(
+        timer.available_actions(),
+        AvailableActions {
+            undo: true,
+            reset: true,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn current_time_is_consistent_for_both_clocks() {
+    for use_utc in [false, true] {
+        let mut timer = timer();
+        timer.use_utc(use_utc);
+
+        let time = timer.snapshot().current_time();
+        assert_eq!(time.real_time, Some(TimeSpan::zero()));
+
+        // Let the clocks drift apart, so it's visible which one is used.
+        let (now, now_utc) = (TimeStamp::now(), AtomicDateTime::now());
+        timer.replay_clock = Some((
+            now - TimeSpan::from_seconds(10.0),
+            now_utc - TimeSpan::from_seconds(12.0),
+        ));
+        timer.start();
+        timer.initialize_game_time();
+        timer.set_loading_times(TimeSpan::from_seconds(3.0));
+        timer.replay_clock = Some((now, now_utc));
+
+        // Only the Real Time follows the UTC clock, the Game Time is always
+        // based on the monotonic clock.
+        let time = timer.snapshot().current_time();
+        let real_time = if use_utc { 12.0 } else { 10.0 };
+        assert_eq!(time.real_time, Some(TimeSpan::from_seconds(real_time)));
+        assert_eq!(time.game_time, Some(TimeSpan::from_seconds(7.0)));
+
+        timer.pause();
+        let first = timer.snapshot().current_time();
+        let second = timer.snapshot().current_time();
+        assert_eq!(first, second);
+    }
+}
+
+#[test]
+fn switching_comparisons_recovers_from_a_missing_comparison() {
+    let mut timer = timer();
+    let comparisons: Vec<String> = timer.run().comparisons().map(String::from).collect();
+
+    timer.switch_to_next_comparison();
+    assert_eq!(timer.current_comparison(), comparisons[1]);
+    timer.switch_to_previous_comparison();
+    timer.switch_to_previous_comparison();
+    assert_eq!(
+        timer.current_comparison(),
+        comparisons[comparisons.len() - 1]
+    );
+
+    let mut state = timer.timer_state(Action::None);
+    state.current_comparison = "Removed Comparison".into();
+    timer.replace_state(&state).unwrap();
+    assert_eq!(timer.current_comparison(), "Removed Comparison");
+
+    timer.switch_to_next_comparison();
+    assert_eq!(timer.current_comparison(), personal_best::NAME);
+
+    timer.replace_state(&state).unwrap();
+    timer.switch_to_previous_comparison();
+    assert_eq!(timer.current_comparison(), personal_best::NAME);
+}
+
+#[test]
+fn set_split_times() {
+    let mut timer = timer();
+    let times = [1.0, 2.0, 3.0].map(|t| {
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(t)))
+            .with_game_time(Some(TimeSpan::from_seconds(t + 0.5)))
+    });
+
+    // An attempt needs to be in progress.
+    assert_eq!(
+        timer.set_split_times(&times),
+        Err(SetSplitTimesError::NoAttempt)
+    );
+
+    timer.start();
+    timer.split();
+    timer.mark_as_unmodified();
+    assert_eq!(
+        timer.set_split_times(&times[..2]),
+        Err(SetSplitTimesError::TimeCountMismatch)
+    );
+    // The attempt hasn't reached the second split yet.
+    assert_eq!(
+        timer.set_split_times(&times),
+        Err(SetSplitTimesError::SplitNotReached { index: 1 })
+    );
+    assert!(!timer.run().has_been_modified());
+    assert!(timer.split_datetime_utc(0).is_some());
+
+    let partial = [times[0], Time::default(), Time::default()];
+    assert_eq!(timer.set_split_times(&partial), Ok(()));
+    assert!(timer.run().has_been_modified());
+    assert_eq!(timer.run().segment(0).split_time(), times[0]);
+    assert_eq!(timer.split_datetime_utc(0), None);
+
+    timer.split();
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.set_split_times(&times), Ok(()));
+    for (segment, time) in timer.run().segments().iter().zip(&times) {
+        assert_eq!(segment.split_time(), *time);
+    }
+}
+
+#[test]
+fn attempt_metadata_is_captured_when_starting() {
+    let mut timer = timer();
+    let metadata = [("Seed".to_owned(), "1234".to_owned())]
+        .into_iter()
+        .collect();
+    timer.set_attempt_metadata(metadata);
+    timer.start();
+
+    // Changing the metadata only affects the next attempt.
+    let metadata = [("Seed".to_owned(), "5678".to_owned())]
+        .into_iter()
+        .collect();
+    timer.set_attempt_metadata(metadata);
+    timer.reset(true);
+
+    let attempt = timer.run().attempt_history().last().unwrap();
+    assert_eq!(attempt.metadata().unwrap()["Seed"], "1234");
+
+    timer.start();
+    timer.reset(true);
+    let attempt = timer.run().attempt_history().last().unwrap();
+    assert_eq!(attempt.metadata().unwrap()["Seed"], "5678");
+}
+
+#[test]
+fn idle_timeout_pauses_running_attempts() {
+    let mut timer = timer();
+    timer.start();
+
+    // Without an idle timeout nothing happens.
+    timer.update_idle();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+
+    timer.set_idle_timeout(Some(TimeSpan::from_seconds(60.0)));
+    timer.update_idle();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+
+    timer.set_idle_timeout(Some(TimeSpan::zero()));
+    timer.update_idle();
+    assert_eq!(timer.current_phase(), TimerPhase::Paused);
+
+    // Only running attempts get paused.
+    timer.reset(true);
+    timer.update_idle();
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+}
+
+#[test]
+fn delta_category() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    let method = TimingMethod::GameTime;
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::Neutral
+    );
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(0.5));
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::AheadGainingTime
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(1.5));
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BehindLosingTime
+    );
+
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(2.2));
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BehindGainingTime
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(2.8));
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BehindLosingTime
+    );
+
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(4.0));
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BehindLosingTime
+    );
+}
+
+#[test]
+fn delta_category_segment_in_progress_is_not_gold_yet() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    let method = TimingMethod::GameTime;
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(0.1));
+    assert_ne!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BestSegment
+    );
+
+    // A target that is faster than the best segments projects a gold.
+    timer.set_transient_comparison(vec![
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(0.5))),
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(1.5))),
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(2.5))),
+    ]);
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BestSegment
+    );
+
+    // The transient comparison is used for the delta as well.
+    timer.set_game_time(TimeSpan::from_seconds(1.2));
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), method),
+        DeltaCategory::BehindLosingTime
+    );
+}
+
+#[test]
+fn delta_category_finished_with_gold() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    run_with_splits(&mut timer, &[1.0, 2.0, 2.5]);
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), TimingMethod::GameTime),
+        DeltaCategory::Neutral
+    );
+
+    start_run(&mut timer);
+    for split in [1.5, 2.5, 2.9] {
+        timer.set_game_time(TimeSpan::from_seconds(split));
+        timer.split();
+    }
+    assert_eq!(
+        timer.delta_category(&timer.snapshot(), TimingMethod::GameTime),
+        DeltaCategory::BestSegment
+    );
+}
+
+#[test]
+fn subtract_times() {
+    let a = Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(5.0)))
+        .with_game_time(Some(TimeSpan::from_seconds(3.0)));
+    let b = Time::new().with_real_time(Some(TimeSpan::from_seconds(7.0)));
+
+    assert_eq!(
+        Timer::subtract_times(a, b, TimingMethod::RealTime),
+        Some(TimeSpan::from_seconds(-2.0))
+    );
+    assert_eq!(Timer::subtract_times(a, b, TimingMethod::GameTime), None);
+    assert_eq!(Timer::subtract_times(b, a, TimingMethod::GameTime), None);
+}
+
+#[test]
+fn current_split_goal() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.5, 3.0]);
+    assert_eq!(timer.current_split_goal(TimingMethod::GameTime), None);
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.current_split_goal(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(1.0))
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(1.5));
+    timer.split();
+    assert_eq!(
+        timer.current_split_goal(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(2.5))
+    );
+
+    timer.split();
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.current_split_goal(TimingMethod::GameTime), None);
+}
+
+#[test]
+fn labeled_pause_intervals() {
+    let mut timer = timer();
+    timer.start();
+    timer.pause_with_label("Loading");
+    assert_eq!(timer.pause_intervals().len(), 1);
+    assert_eq!(timer.pause_intervals()[0].ended(), None);
+
+    let state = timer.timer_state(Action::None);
+    timer.resume();
+    assert!(timer.pause_intervals()[0].ended().is_some());
+
+    // The pause intervals are part of the Timer State.
+    timer.replace_state(&state).unwrap();
+    assert_eq!(timer.pause_intervals().len(), 1);
+    assert_eq!(timer.pause_intervals()[0].label(), "Loading");
+    assert_eq!(timer.pause_intervals()[0].ended(), None);
+
+    timer.resume();
+    timer.pause();
+    timer.reset(true);
+
+    let intervals = timer
+        .run()
+        .attempt_history()
+        .last()
+        .unwrap()
+        .pause_intervals();
+    let labels = intervals.iter().map(|i| i.label()).collect::<Vec<_>>();
+    assert_eq!(labels, ["Loading", ""]);
+    assert!(intervals.iter().all(|i| i.duration().is_some()));
+    assert!(timer.pause_intervals().is_empty());
+}
+
+#[test]
+fn sessions_keep_sub_millisecond_times() {
+    let time = TimeSpan::from_seconds(1.2345678);
+
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(time);
+    timer.split();
+    assert_eq!(timer.state_precision(), Some(3));
+
+    let session = timer.save_session().unwrap();
+    let restored = Timer::load_session(&session).unwrap();
+
+    let restored_time = restored.run().segment(0).split_time().game_time.unwrap();
+    assert!((restored_time - time).total_seconds().abs() < 1e-6);
+}
+
+#[test]
+fn sessions_can_be_restored() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.5));
+    timer.split();
+
+    let session = timer.save_session().unwrap();
+    let restored = Timer::load_session(&session).unwrap();
+
+    assert_eq!(restored.current_phase(), TimerPhase::Running);
+    assert_eq!(restored.current_split_index(), Some(1));
+    assert_eq!(restored.current_timing_method(), TimingMethod::GameTime);
+    assert_eq!(
+        restored.run().segment(0).split_time().game_time,
+        Some(TimeSpan::from_seconds(1.5))
+    );
+    assert_eq!(
+        restored
+            .run()
+            .segment(2)
+            .personal_best_split_time()
+            .game_time,
+        Some(TimeSpan::from_seconds(3.0))
+    );
+    assert_eq!(
+        restored.run().attempt_history().len(),
+        timer.run().attempt_history().len()
+    );
+
+    assert!(matches!(
+        Timer::load_session(b"not a session"),
+        Err(TimerSessionError::Json { .. })
+    ));
+
+    let mut session: serde_json::Value = serde_json::from_slice(&session).unwrap();
+    session["state"]["splits"].as_array_mut().unwrap().pop();
+    let session = serde_json::to_vec(&session).unwrap();
+    assert!(matches!(
+        Timer::load_session(&session),
+        Err(TimerSessionError::InvalidState {
+            issue: StateIssue::SegmentCountMismatch {
+                expected: 3,
+                actual: 2
+            }
+        })
+    ));
+}
+
+#[test]
+fn validating_state_collects_all_issues() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.split();
+
+    let mut state = timer.timer_state(Action::None);
+    assert_eq!(timer.validate_state(&state), Ok(()));
+
+    state.splits.pop();
+    state.phase = String::from("Sprinting");
+    state.time_paused_at = f64::NAN;
+    state.start_time_utc.time = String::from("yesterday");
+
+    assert_eq!(
+        timer.validate_state(&state),
+        Err(vec![
+            StateIssue::SegmentCountMismatch {
+                expected: 3,
+                actual: 2,
+            },
+            StateIssue::UnknownPhase {
+                phase: String::from("Sprinting"),
+            },
+            StateIssue::NonFiniteTime {
+                field: "time_paused_at",
+            },
+            StateIssue::InvalidTimestamp {
+                field: "start_time_utc",
+            },
+        ])
+    );
+}
+
+#[test]
+fn action_log_records_actions_when_enabled() {
+    let mut timer = timer();
+    timer.start();
+    assert!(timer.action_log().is_empty());
+
+    timer.enable_action_log();
+    timer.split();
+    timer.pause();
+    timer.resume();
+
+    timer.initialize_game_time();
+    timer.set_loading_times(TimeSpan::from_seconds(1.0));
+
+    let actions: Vec<_> = timer.action_log().iter().map(|&(_, a)| a).collect();
+    assert_eq!(
+        actions,
+        [
+            LoggedAction::Action(Action::Split),
+            LoggedAction::Action(Action::Pause),
+            LoggedAction::Action(Action::Resume),
+            LoggedAction::InitializeGameTime,
+            LoggedAction::SetLoadingTimes(TimeSpan::from_seconds(1.0)),
+        ]
+    );
+    assert!(timer.action_log()[0].0 >= TimeSpan::zero());
+    assert!(timer.action_log()[0].0 <= timer.action_log()[2].0);
+
+    timer.set_action_log_capacity(2);
+    assert_eq!(timer.action_log().len(), 2);
+    timer.split();
+    let actions: Vec<_> = timer.action_log().iter().map(|&(_, a)| a).collect();
+    assert_eq!(
+        actions,
+        [
+            LoggedAction::SetLoadingTimes(TimeSpan::from_seconds(1.0)),
+            LoggedAction::Action(Action::Split),
+        ]
+    );
+
+    timer.set_action_log_capacity(0);
+    assert!(timer.action_log().is_empty());
+    timer.split();
+    assert!(timer.action_log().is_empty());
+
+    timer.disable_action_log();
+    assert!(timer.action_log().is_empty());
+}
+
+#[test]
+fn reset_reports_whether_an_attempt_was_reset() {
+    let mut timer = timer();
+    assert!(!timer.reset(true));
+
+    start_run(&mut timer);
+    assert!(timer.reset(true));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert!(!timer.reset(true));
+}
+
+#[test]
+fn ended_game_time_only_run_falls_back_to_last_real_time() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.split();
+    timer.split();
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    let last_real_time = timer.run().segment(1).split_time().real_time;
+    timer
+        .run
+        .segment_mut(2)
+        .set_split_time(Time::new().with_game_time(Some(TimeSpan::from_seconds(5.0))));
+
+    let time = timer.snapshot().current_time();
+    assert!(last_real_time.is_some());
+    assert_eq!(time.real_time, last_real_time);
+    assert_eq!(time.game_time, Some(TimeSpan::from_seconds(5.0)));
+}
+
+#[test]
+fn temporary_variables_can_be_listed_and_cleared() {
+    let mut timer = timer();
+    timer.set_custom_variable("HP", "100");
+    timer.set_custom_variable("Room", "Hub");
+    timer
+        .set_run({
+            let mut run = timer.run().clone();
+            run.metadata_mut()
+                .custom_variable_mut("Category")
+                .permanent()
+                .set_value("Any%");
+            run
+        })
+        .unwrap();
+
+    let temporaries: Vec<_> = timer.temporary_variables().collect();
+    assert_eq!(temporaries, [("HP", "100"), ("Room", "Hub")]);
+
+    timer.clear_temporary_variables();
+    assert_eq!(timer.temporary_variables().count(), 0);
+    assert_eq!(
+        timer.run().metadata().custom_variable_value("Category"),
+        Some("Any%")
+    );
+}
+
+#[test]
+fn split_filter_can_reject_splits() {
+    let mut timer = timer();
+    timer.set_split_filter(|snapshot| snapshot.current_split().unwrap().name() != "B");
+    start_run(&mut timer);
+
+    timer.split();
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.split();
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(timer.run().segment(1).split_time(), Time::default());
+
+    timer.clear_split_filter();
+    timer.split();
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn split_debounce_ignores_double_splits() {
+    let mut timer = timer();
+    timer.set_split_debounce(Some(TimeSpan::from_seconds(3600.0)));
+    start_run(&mut timer);
+
+    timer.split();
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.split();
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.set_split_debounce(None);
+    timer.split();
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn last_action_is_tracked() {
+    let mut timer = timer();
+    assert_eq!(timer.last_action(), Action::None);
+
+    timer.start();
+    assert_eq!(timer.last_action(), Action::Start);
+
+    timer.split();
+    timer.undo_split();
+    assert_eq!(timer.last_action(), Action::Undo);
+
+    timer.reset(false);
+    assert_eq!(timer.last_action(), Action::Reset);
+}
+
+#[test]
+fn input_latency_shifts_start_and_split_times() {
+    let mut timer = timer();
+    let latency = TimeSpan::from_seconds(10.0);
+    timer.set_input_latency(latency);
+    timer.start();
+
+    let current_time = timer.snapshot().current_time().real_time.unwrap();
+    assert!(current_time >= latency);
+
+    timer.split();
+    let split_time = timer.run().segment(0).split_time().real_time.unwrap();
+    assert!(split_time >= TimeSpan::zero());
+    assert!(split_time < latency);
+}
+
+#[test]
+fn segment_results_vs_best_compare_against_best_segments() {
+    let mut timer = timer();
+    run_with_splits_opt(&mut timer, &[Some(1.0), Some(3.0), Some(6.0)]);
+    run_with_splits_opt(&mut timer, &[Some(2.0), None, Some(5.0)]);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.5));
+    timer.split();
+    timer.skip_split();
+    timer.set_game_time(TimeSpan::from_seconds(7.0));
+    timer.split();
+
+    assert_eq!(
+        timer.segment_results_vs_best(TimingMethod::GameTime),
+        [Some(TimeSpan::from_seconds(0.5)), None, None],
+    );
+    timer.reset(false);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(0.5));
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(3.0));
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(6.0));
+    timer.split();
+
+    assert_eq!(
+        timer.segment_results_vs_best(TimingMethod::GameTime),
+        [
+            Some(TimeSpan::from_seconds(-0.5)),
+            Some(TimeSpan::from_seconds(0.5)),
+            Some(TimeSpan::zero()),
+        ],
+    );
+}
+
+#[test]
+fn resetting_with_history_only_keeps_bests_untouched() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+
+    start_run(&mut timer);
+    for split in [0.5, 1.0, 1.5] {
+        timer.set_game_time(TimeSpan::from_seconds(split));
+        timer.split();
+    }
+    assert!(timer.reset_with(ResetKind::SaveHistoryOnly));
+
+    let run = timer.run();
+    assert_eq!(run.attempt_history().len(), 2);
+    let attempt = &run.attempt_history()[1];
+    assert_eq!(attempt.time().game_time, Some(TimeSpan::from_seconds(1.5)));
+    assert!(run
+        .segment(2)
+        .segment_history()
+        .get(attempt.index())
+        .is_some());
+    assert_eq!(
+        run.segment(0).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(1.0))
+    );
+    // The faster segment time doesn't sneak into the history either.
+    assert_eq!(
+        run.segment(0)
+            .segment_history()
+            .get(attempt.index())
+            .unwrap()
+            .game_time,
+        Some(TimeSpan::from_seconds(1.0))
+    );
+    assert_eq!(
+        run.segment(2).personal_best_split_time().game_time,
+        Some(TimeSpan::from_seconds(3.0))
+    );
+
+    start_run(&mut timer);
+    assert!(timer.reset_with(ResetKind::Discard));
+    assert_eq!(timer.run().attempt_history().len(), 2);
+}
+
+#[test]
+fn finished_attempts_are_counted() {
+    let mut timer = timer();
+    assert_eq!(timer.finished_attempt_count(), 0);
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    run_with_splits(&mut timer, &[1.0]);
+    run_with_splits_opt(&mut timer, &[Some(1.0), None, Some(3.0)]);
+
+    assert_eq!(timer.run().attempt_count(), 3);
+    assert_eq!(timer.finished_attempt_count(), 2);
+}
+
+#[test]
+fn custom_comparisons_can_be_renamed_and_removed() {
+    let mut run = run();
+    run.add_custom_comparison("Goal").unwrap();
+    let mut timer = Timer::new(run).unwrap();
+    timer.set_current_comparison("Goal").unwrap();
+
+    timer.rename_comparison("Goal", "Target").unwrap();
+    assert_eq!(timer.current_comparison(), "Target");
+    assert!(timer.run().comparisons().any(|c| c == "Target"));
+    assert!(!timer.run().comparisons().any(|c| c == "Goal"));
+
+    assert!(matches!(
+        timer.rename_comparison("Target", personal_best::NAME),
+        Err(TimerComparisonError::InvalidName { .. })
+    ));
+    assert!(matches!(
+        timer.rename_comparison(personal_best::NAME, "PB"),
+        Err(TimerComparisonError::NotCustom)
+    ));
+    assert!(matches!(
+        timer.remove_comparison(crate::comparison::best_segments::NAME),
+        Err(TimerComparisonError::NotCustom)
+    ));
+
+    timer.remove_comparison("Target").unwrap();
+    assert_eq!(timer.current_comparison(), personal_best::NAME);
+    assert!(!timer.run().comparisons().any(|c| c == "Target"));
+}
+
+#[test]
+fn timer_states_can_be_streamed_as_ndjson() {
+    let mut timer = timer();
+    let mut buf = Vec::new();
+
+    timer
+        .timer_state(Action::None)
+        .write_ndjson(&mut buf)
+        .unwrap();
+    timer.start();
+    timer.split();
+    timer
+        .timer_state(Action::Split)
+        .write_ndjson(&mut buf)
+        .unwrap();
+
+    let text = String::from_utf8(buf).unwrap();
+    let states: Vec<TimerState> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(states.len(), 2);
+    assert_eq!(states[0].phase, "NotRunning");
+    assert_eq!(states[1].current_split_index, Some(1));
+    assert!(matches!(states[1].action, Action::Split));
+}
+
+#[test]
+fn repairing_state_clamps_the_split_index() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    assert_eq!(timer.repair_state(), None);
+
+    let mut state = timer.timer_state(Action::None);
+    state.current_split_index = Some(3);
+    timer.replace_state(&state).unwrap();
+
+    assert_eq!(
+        timer.repair_state(),
+        Some(StateIssue::InvalidSplitIndex { index: Some(3) })
+    );
+    assert_eq!(timer.current_split_index(), Some(2));
+    assert_eq!(timer.current_split().unwrap().name(), "C");
+
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+}
+
+#[test]
+fn repairing_state_fixes_index_of_ended_and_not_running_timers() {
+    let mut timer = timer();
+    let mut state = timer.timer_state(Action::None);
+    state.current_split_index = Some(1);
+    timer.replace_state(&state).unwrap();
+    assert!(timer.repair_state().is_some());
+    assert_eq!(timer.current_split_index(), None);
+
+    state.phase = String::from("Ended");
+    timer.replace_state(&state).unwrap();
+    assert!(timer.repair_state().is_some());
+    assert_eq!(timer.current_split_index(), Some(3));
+}
+
+#[test]
+fn timer_state_contains_game_time_initialization() {
+    let mut timer = timer();
+    timer.start();
+    assert!(!timer.timer_state(Action::None).game_time_initialized);
+
+    timer.initialize_game_time();
+    let state = timer.timer_state(Action::None);
+    assert!(state.game_time_initialized);
+
+    let mut json: serde_json::Value = serde_json::from_str(&state.to_json()).unwrap();
+    json.as_object_mut()
+        .unwrap()
+        .remove("game_time_initialized");
+    let state: TimerState = serde_json::from_value(json).unwrap();
+    assert!(!state.game_time_initialized);
+}
+
+#[test]
+fn practice_mode_doesnt_store_attempts() {
+    let mut timer = timer();
+    timer.set_practice_mode(true);
+    assert!(timer.is_practice_mode());
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+
+    let run = timer.run();
+    assert!(run.attempt_history().is_empty());
+    assert_eq!(run.segment(2).personal_best_split_time(), Time::default());
+    assert_eq!(run.segment(0).best_segment_time(), Time::default());
+
+    timer.set_practice_mode(false);
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    assert_eq!(timer.run().attempt_history().len(), 1);
+}
+
+#[test]
+fn game_time_smoothing_extrapolates_between_updates() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.pause();
+    timer.set_game_time_smoothing(true);
+
+    let game_time_at = |timer: &mut Timer, real_time: f64| {
+        timer.time_paused_at = TimeSpan::from_seconds(real_time);
+        timer.snapshot().current_time().game_time.unwrap()
+    };
+
+    game_time_at(&mut timer, 1.0);
+    timer.set_game_time(TimeSpan::from_seconds(0.5));
+    assert_eq!(game_time_at(&mut timer, 1.5), TimeSpan::from_seconds(0.5));
+
+    game_time_at(&mut timer, 2.0);
+    timer.set_game_time(TimeSpan::from_seconds(1.0));
+    assert_eq!(game_time_at(&mut timer, 4.0), TimeSpan::from_seconds(2.0));
+
+    timer.set_game_time(TimeSpan::from_seconds(1.8));
+    assert_eq!(game_time_at(&mut timer, 4.0), TimeSpan::from_seconds(1.8));
+
+    timer.set_game_time_smoothing(false);
+    assert_eq!(game_time_at(&mut timer, 6.0), TimeSpan::from_seconds(1.8));
+}
+
+#[test]
+fn projected_time_adds_remaining_comparison_time() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+
+    assert_eq!(
+        timer.projected_time(&timer.snapshot(), TimingMethod::GameTime),
+        None
+    );
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+    assert_eq!(
+        timer.projected_time(&timer.snapshot(), TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(7.0))
+    );
+
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(2.5));
+    assert_eq!(
+        timer.projected_time(&timer.snapshot(), TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(5.5))
+    );
+}
+
+#[test]
+fn snapshot_captures_phase_and_split_index() {
+    let mut timer = timer();
+    let snapshot = timer.snapshot();
+    assert_eq!(snapshot.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(snapshot.current_split_index(), None);
+
+    timer.start();
+    timer.split();
+    let snapshot = timer.snapshot();
+    assert_eq!(snapshot.current_phase(), TimerPhase::Running);
+    assert_eq!(snapshot.current_split_index(), Some(1));
+}
+
+#[test]
+fn min_segment_time_rejects_short_segments() {
+    let mut timer = timer();
+    timer.set_min_segment_time(Some(TimeSpan::from_seconds(1.0)));
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(0.5));
+    assert!(!timer.split());
+    assert_eq!(timer.current_split_index(), Some(0));
+
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+    assert!(timer.split());
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+    assert!(!timer.split());
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.set_min_segment_time(None);
+    assert!(timer.split());
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn attempts_can_be_compared() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits_opt(&mut timer, &[Some(2.0), None, Some(4.0)]);
+    run_with_splits(&mut timer, &[1.5]);
+
+    let history = timer.run().attempt_history();
+    let (first, second, third) = (history[0].index(), history[1].index(), history[2].index());
+
+    assert_eq!(
+        timer.compare_attempts(second, first, TimingMethod::GameTime),
+        [Some(TimeSpan::from_seconds(1.0)), None, None],
+    );
+    assert_eq!(
+        timer.compare_attempts(first, third, TimingMethod::GameTime),
+        [Some(TimeSpan::from_seconds(-0.5)), None, None],
+    );
+}
+
+#[test]
+fn beating_the_personal_best_is_reported() {
+    std::thread_local! {
+        static STATES: core::cell::RefCell<Vec<TimerState>> = Default::default();
+    }
+
+    let mut timer = timer();
+    timer.set_on_timer_change(|state| STATES.with(|s| s.borrow_mut().push(state.clone())));
+    timer.enable_action_log();
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    run_with_splits(&mut timer, &[1.0, 2.0, 4.0]);
+    run_with_splits(&mut timer, &[1.0, 2.0, 2.5]);
+
+    let actions: Vec<_> = timer.action_log().iter().map(|&(_, a)| a).collect();
+    assert_eq!(
+        actions
+            .iter()
+            .filter(|&&a| a == LoggedAction::Action(Action::NewPersonalBest))
+            .count(),
+        2
+    );
+    assert_eq!(
+        actions[actions.len() - 2..],
+        [
+            LoggedAction::Action(Action::NewPersonalBest),
+            LoggedAction::Reset(ResetKind::SaveAll),
+        ]
+    );
+
+    let improvements: Vec<_> = STATES.with(|s| {
+        s.borrow()
+            .iter()
+            .filter(|s| matches!(s.action, Action::NewPersonalBest))
+            .map(|s| s.personal_best_improvement)
+            .collect()
+    });
+    assert_eq!(improvements, [None, Some(0.5)]);
+}
+
+#[test]
+fn comparisons_can_be_checked_for_timing_methods() {
+    let mut timer = timer();
+    assert!(!timer.comparison_has_method(personal_best::NAME, TimingMethod::GameTime));
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    assert!(timer.comparison_has_method(personal_best::NAME, TimingMethod::GameTime));
+    assert!(timer.comparison_has_method(personal_best::NAME, TimingMethod::RealTime));
+
+    let mut run = timer.run().clone();
+    run.add_custom_comparison("Real Time Only").unwrap();
+    *run.segment_mut(0).comparison_mut("Real Time Only") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(1.0)));
+    timer.set_run(run).unwrap();
+
+    assert!(timer.comparison_has_method("Real Time Only", TimingMethod::RealTime));
+    assert!(!timer.comparison_has_method("Real Time Only", TimingMethod::GameTime));
+    assert!(!timer.comparison_has_method("Missing", TimingMethod::RealTime));
+}
+
+#[test]
+fn game_time_can_be_synced_to_real_time() {
+    let mut timer = timer();
+    timer.start();
+    timer.initialize_game_time();
+    timer.set_loading_times(TimeSpan::from_seconds(5.0));
+
+    timer.sync_game_time_to_real_time();
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+    assert!(timer.is_game_time_initialized());
+
+    timer.pause();
+    let time = timer.snapshot().current_time();
+    assert_eq!(time.game_time, time.real_time);
+}
+
+#[test]
+fn timers_can_be_configured_with_a_builder() {
+    let timer = Timer::builder(run())
+        .timing_method(TimingMethod::GameTime)
+        .use_utc(false)
+        .comparison(crate::comparison::best_segments::NAME)
+        .build()
+        .unwrap();
+
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+    assert_eq!(
+        timer.current_comparison(),
+        crate::comparison::best_segments::NAME
+    );
+
+    assert!(matches!(
+        Timer::builder(run()).comparison("Missing").build(),
+        Err(TimerCreationError::UnknownComparison { name }) if name == "Missing"
+    ));
+    assert!(matches!(
+        Timer::builder(Run::new()).build(),
+        Err(TimerCreationError::EmptyRun)
+    ));
+}
+
+#[test]
+fn best_possible_comparison_follows_best_segments() {
+    let mut timer = timer();
+    timer.set_comparison_to_best_possible();
+    assert_eq!(
+        timer.current_comparison(),
+        crate::comparison::best_possible::NAME
+    );
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 3.5, 5.0]);
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.comparison_time(2).game_time,
+        Some(TimeSpan::from_seconds(4.0))
+    );
+}
+
+#[test]
+fn best_possible_comparison_is_only_added_on_demand() {
+    let mut timer = timer();
+    assert!(timer
+        .set_current_comparison(crate::comparison::best_possible::NAME)
+        .is_err());
+
+    timer.set_comparison_to_best_possible();
+    timer
+        .set_current_comparison(crate::comparison::best_possible::NAME)
+        .unwrap();
+}
+
+#[test]
+fn best_possible_comparison_keeps_custom_comparison_with_the_same_name() {
+    let name = crate::comparison::best_possible::NAME;
+    let time = Time::new().with_real_time(Some(TimeSpan::from_seconds(42.0)));
+
+    let mut run = run();
+    run.add_custom_comparison(name).unwrap();
+    *run.segment_mut(0).comparison_mut(name) = time;
+    let mut timer = Timer::new(run).unwrap();
+
+    timer.set_comparison_to_best_possible();
+    assert!(timer.run().custom_comparisons().iter().all(|c| c != name));
+    assert_eq!(
+        timer.run().segment(0).comparison("Best Possible (Custom)"),
+        time
+    );
+    assert_eq!(timer.run().segment(0).comparison(name).real_time, None);
+}
+
+#[test]
+fn replacing_state_with_malformed_timestamp_fails() {
+    let mut timer = timer();
+    let mut state = timer.timer_state(Action::None);
+    start_run(&mut timer);
+
+    state.attempt_started = timer.timer_state(Action::None).attempt_started;
+    state.attempt_started.as_mut().unwrap().time = String::from("yesterday");
+    assert_eq!(
+        timer.replace_state(&state),
+        Err(StateIssue::InvalidTimestamp {
+            field: "attempt_started"
+        })
+    );
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+
+    state.splits.pop();
+    assert_eq!(
+        timer.replace_state(&state),
+        Err(StateIssue::SegmentCountMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+fn countdown_splits() {
+    let mut run = run();
+    run.set_offset(TimeSpan::from_seconds(-100.0));
+    run.segment_mut(0).set_countdown_splittable(true);
+    let mut timer = Timer::new(run).unwrap();
+
+    timer.start();
+    assert!(timer.split());
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert!(timer.run().segment(0).split_time().real_time.unwrap() < TimeSpan::zero());
+
+    assert!(!timer.split());
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.set_allow_countdown_splits(true);
+    assert!(timer.allows_countdown_splits());
+    assert!(timer.split());
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn run_summary() {
+    let mut run = run();
+    run.set_game_name("Game");
+    run.set_category_name("Any%");
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(2.0), None, Some(5.0)]);
+
+    let summary = timer.run_summary(TimingMethod::GameTime);
+    assert_eq!(summary.game_name, "Game");
+    assert_eq!(summary.category_name, "Any%");
+    assert_eq!(summary.final_time, Some(5.0));
+    assert_eq!(summary.comparison, personal_best::NAME);
+    assert_eq!(summary.sum_of_best, Some(4.0));
+    assert_eq!(summary.attempt_count, 2);
+
+    let splits: Vec<_> = summary
+        .segments
+        .iter()
+        .map(|s| (s.name.as_str(), s.split_time, s.delta))
+        .collect();
+    assert_eq!(
+        splits,
+        [
+            ("A", Some(2.0), Some(1.0)),
+            ("B", None, None),
+            ("C", Some(5.0), Some(-1.0)),
+        ]
+    );
+
+    timer.undo_split();
+    let summary = timer.run_summary(TimingMethod::GameTime);
+    assert_eq!(summary.final_time, None);
+    assert_eq!(summary.segments[2].split_time, None);
+}
+
+#[test]
+fn restoring_state_with_paused_game_time_keeps_times() {
+    let mut timer = timer();
+    timer.use_utc(false);
+    timer.start();
+    timer.initialize_game_time();
+    timer.pause_game_time();
+    timer.set_game_time(TimeSpan::from_seconds(42.0));
+    timer.start_time = timer.start_time - TimeSpan::from_seconds(50.0);
+    timer.adjusted_start_time = timer.adjusted_start_time - TimeSpan::from_seconds(50.0);
+    timer.start_time_utc = timer.start_time_utc - TimeSpan::from_seconds(50.0);
+    timer.adjusted_start_time_utc = timer.adjusted_start_time_utc - TimeSpan::from_seconds(50.0);
+
+    let json = serde_json::to_vec(&timer.timer_state(Action::None)).unwrap();
+    let state: TimerState = serde_json::from_slice(&json).unwrap();
+
+    let mut restored = Timer::new(run()).unwrap();
+    restored.use_utc(false);
+    restored.replace_state(&state).unwrap();
+    let time = restored.snapshot().current_time();
+    assert_eq!(time.game_time, Some(TimeSpan::from_seconds(42.0)));
+    assert!(time.real_time.unwrap() >= TimeSpan::from_seconds(50.0));
+
+    restored.resume_game_time();
+    let game_time = restored.snapshot().current_time().game_time.unwrap();
+    assert!(game_time >= TimeSpan::from_seconds(42.0));
+    assert!(game_time < TimeSpan::from_seconds(43.0));
+}
+
+#[test]
+fn current_time_for_timing_method() {
+    let mut timer = timer();
+    assert_eq!(
+        timer.current_time_for(TimingMethod::RealTime),
+        Some(TimeSpan::zero())
+    );
+
+    timer.start();
+    assert_eq!(timer.current_time_for(TimingMethod::GameTime), None);
+
+    timer.initialize_game_time();
+    timer.pause_game_time();
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    assert_eq!(
+        timer.current_time_for(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(5.0))
+    );
+
+    timer.pause();
+    assert_eq!(
+        timer.current_time_for(TimingMethod::RealTime),
+        timer.snapshot().current_time().real_time
+    );
+}
+
+#[test]
+fn non_monotonic_splits() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(7.0)]);
+    assert!(!timer.has_non_monotonic_splits(TimingMethod::GameTime));
+
+    let time = |t| {
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(t)))
+            .with_game_time(Some(TimeSpan::from_seconds(t)))
+    };
+    timer
+        .set_split_times(&[time(5.0), Time::default(), time(3.0)])
+        .unwrap();
+    assert!(timer.has_non_monotonic_splits(TimingMethod::RealTime));
+    assert!(timer.has_non_monotonic_splits(TimingMethod::GameTime));
+
+    timer.fix_non_monotonic_splits();
+    assert!(!timer.has_non_monotonic_splits(TimingMethod::RealTime));
+    assert!(!timer.has_non_monotonic_splits(TimingMethod::GameTime));
+    assert_eq!(timer.run().segment(1).split_time(), Time::default());
+    assert_eq!(timer.run().segment(2).split_time(), time(5.0));
+}
+
+#[test]
+fn status() {
+    let mut timer = timer();
+    let status = timer.status();
+    assert_eq!(status.phase, TimerPhase::NotRunning);
+    assert_eq!(status.current_split_index, None);
+    assert_eq!(status.current_timing_method, TimingMethod::RealTime);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(3.0));
+    timer.split();
+    let status = timer.status();
+    assert_eq!(status.phase, TimerPhase::Running);
+    assert_eq!(status.current_split_index, Some(1));
+    assert_eq!(status.current_timing_method, TimingMethod::GameTime);
+    assert_eq!(
+        status.current_time.game_time,
+        Some(TimeSpan::from_seconds(3.0))
+    );
+}
+
+#[test]
+fn final_time_override() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    let final_time = Time::new().with_game_time(Some(TimeSpan::from_seconds(9.5)));
+    timer.set_final_time_override(Some(final_time));
+
+    timer.set_game_time(TimeSpan::from_seconds(3.0));
+    timer.split();
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(TimeSpan::from_seconds(3.0))
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(6.0));
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(10.0));
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        timer.run().segment(2).split_time().game_time,
+        final_time.game_time
+    );
+    assert!(timer.run().segment(2).split_time().real_time.is_some());
+
+    timer.reset(true);
+    assert_eq!(timer.final_time_override(), None);
+}
+
+#[test]
+fn segment_time_for_index() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(2.0), None, Some(7.5)]);
+
+    let method = TimingMethod::GameTime;
+    assert_eq!(
+        timer.segment_time_for(0, method),
+        Some(TimeSpan::from_seconds(2.0))
+    );
+    assert_eq!(timer.segment_time_for(1, method), None);
+    assert_eq!(timer.segment_time_for(2, method), None);
+    assert_eq!(timer.segment_time_for(3, method), None);
+
+    timer.undo_split();
+    timer.undo_split();
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0), Some(7.5)]);
+    assert_eq!(
+        timer.segment_time_for(1, method),
+        Some(TimeSpan::from_seconds(2.0))
+    );
+    assert_eq!(
+        timer.segment_time_for(2, method),
+        Some(TimeSpan::from_seconds(3.5))
+    );
+}
+
+#[test]
+fn attempt_loading_times_are_kept() {
+    let mut timer = timer();
+    timer.start();
+    timer.initialize_game_time();
+    timer.set_loading_times(TimeSpan::from_seconds(3.0));
+    timer.reset(true);
+
+    timer.start();
+    timer.reset(true);
+
+    let indices: Vec<_> = timer
+        .run()
+        .attempt_history()
+        .iter()
+        .map(|a| a.index())
+        .collect();
+    assert_eq!(
+        timer.attempt_loading_time(indices[0]),
+        Some(TimeSpan::from_seconds(3.0))
+    );
+    assert_eq!(timer.attempt_loading_time(indices[1]), None);
+    assert_eq!(timer.attempt_loading_time(indices[1] + 1), None);
+
+    timer.start();
+    timer.initialize_game_time();
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+
+    let session = timer.save_session().unwrap();
+    let timer = Timer::load_session(&session).unwrap();
+    assert_eq!(
+        timer.attempt_loading_time(indices[0]),
+        Some(TimeSpan::from_seconds(3.0))
+    );
+}
+
+#[test]
+fn following_a_remote_timer() {
+    let mut remote = timer();
+    remote.use_utc(false);
+    start_run(&mut remote);
+    remote.set_game_time(TimeSpan::from_seconds(2.0));
+    remote.split();
+
+    let mut mirror = timer();
+    mirror.use_utc(false);
+    mirror
+        .apply_remote_state(&remote.timer_state(Action::None))
+        .unwrap();
+    assert!(mirror.is_following());
+    assert_eq!(mirror.current_phase(), TimerPhase::Running);
+    assert_eq!(mirror.current_split_index(), Some(1));
+    assert_eq!(mirror.available_actions(), AvailableActions::default());
+
+    mirror.split();
+    mirror.reset(true);
+    assert_eq!(mirror.current_split_index(), Some(1));
+
+    let remote_time = remote.snapshot().current_time().real_time.unwrap();
+    let mirror_time = mirror.snapshot().current_time().real_time.unwrap();
+    let difference = (mirror_time - remote_time).total_seconds().abs();
+    assert!(difference < 1.0);
+
+    remote.split();
+    mirror
+        .apply_remote_state(&remote.timer_state(Action::Split))
+        .unwrap();
+    assert_eq!(mirror.current_split_index(), Some(2));
+
+    mirror.stop_following();
+    assert!(!mirror.is_following());
+    assert!(mirror.split());
+    assert_eq!(mirror.current_phase(), TimerPhase::Ended);
+}
+
+#[test]
+fn state_times_are_rounded() {
+    let mut timer = timer();
+    assert_eq!(timer.state_precision(), Some(3));
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(123.456_789));
+    timer.split();
+
+    let json = timer.timer_state(Action::None).to_json();
+    assert!(json.contains("\"game_time\":123.457}"));
+
+    timer.set_state_precision(Some(1));
+    let state = timer.timer_state(Action::None);
+    assert!(state.to_json().contains("\"game_time\":123.5}"));
+
+    timer.set_state_precision(None);
+    let mut state = timer.timer_state(Action::None);
+    assert!(state.to_json().contains("\"game_time\":123.456789}"));
+
+    state.round_times(2);
+    assert!(state.to_json().contains("\"game_time\":123.46}"));
+}
+
+#[test]
+fn active_play_time_excludes_loading_times() {
+    let mut timer = timer();
+    timer.use_utc(false);
+    assert_eq!(timer.active_play_time(), TimeSpan::zero());
+
+    timer.start();
+    timer.start_time = timer.start_time - TimeSpan::from_seconds(10.0);
+    timer.adjusted_start_time = timer.adjusted_start_time - TimeSpan::from_seconds(10.0);
+    let seconds = |timer: &Timer| timer.active_play_time().total_seconds();
+    assert!((10.0..11.0).contains(&seconds(&timer)));
+
+    timer.initialize_game_time();
+    timer.set_loading_times(TimeSpan::from_seconds(4.0));
+    assert!((6.0..7.0).contains(&seconds(&timer)));
+
+    timer.pause_game_time();
+    timer.set_game_time(TimeSpan::from_seconds(3.0));
+    assert!((3.0..4.0).contains(&seconds(&timer)));
+
+    timer.reset(false);
+    assert_eq!(timer.active_play_time(), TimeSpan::zero());
+}
+
+#[test]
+fn new_personal_best_is_reported_before_the_attempt_is_cleared() {
+    std::thread_local! {
+        static STATES: core::cell::RefCell<Vec<TimerState>> = Default::default();
+    }
+
+    let mut timer = timer();
+    timer.set_on_timer_change(|state| STATES.with(|s| s.borrow_mut().push(state.clone())));
+    start_run(&mut timer);
+    timer.pause_with_label("Loading");
+    timer.resume();
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), Some(2.0), Some(3.0)]);
+    timer.reset(true);
+
+    let state = STATES.with(|s| {
+        s.borrow()
+            .iter()
+            .find(|s| s.action == Action::NewPersonalBest)
+            .cloned()
+            .unwrap()
+    });
+    assert_eq!(state.phase, "Ended");
+    assert_eq!(state.splits[2].game_time, Some(3.0));
+    assert_eq!(state.pause_intervals.len(), 1);
+    assert!(state.loading_times.is_some());
+}
+
+#[test]
+fn shared_timer_readers_never_observe_partial_resets() {
+    let timer = timer().into_shared();
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let timer = timer.clone();
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    let timer = timer.read().unwrap();
+                    let has_split_times = timer
+                        .run()
+                        .segments()
+                        .iter()
+                        .any(|s| s.split_time() != Time::default());
+                    if timer.current_phase() == TimerPhase::NotRunning {
+                        assert!(!has_split_times);
+                        assert_eq!(timer.current_split_index(), None);
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..100 {
+        start_run(&mut timer.write().unwrap());
+        for split in [1.0, 2.0, 3.0] {
+            let mut timer = timer.write().unwrap();
+            timer.set_game_time(TimeSpan::from_seconds(split));
+            timer.split();
+        }
+        timer.write().unwrap().reset(true);
+    }
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
+
+#[test]
+fn comparison_summaries() {
+    let mut timer = timer();
+    let summaries = timer.comparison_summaries(TimingMethod::GameTime);
+    let names: Vec<_> = summaries.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, timer.run().comparisons().collect::<Vec<_>>());
+    assert!(summaries.iter().all(|(_, time)| time.is_none()));
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    run_with_splits(&mut timer, &[0.5, 2.5, 3.5]);
+
+    let summaries = timer.comparison_summaries(TimingMethod::GameTime);
+    let final_time = |name| {
+        summaries
+            .iter()
+            .find(|(n, _)| n == name)
+            .and_then(|&(_, time)| time)
+    };
+    assert_eq!(
+        final_time(personal_best::NAME),
+        Some(TimeSpan::from_seconds(3.0))
+    );
+    assert_eq!(
+        final_time(crate::comparison::best_segments::NAME),
+        Some(TimeSpan::from_seconds(2.5))
+    );
+    assert_eq!(
+        timer.comparison_summaries(TimingMethod::RealTime)[0].1,
+        timer.run().segment(2).personal_best_split_time().real_time
+    );
+}
+
+#[test]
+fn toggling_the_timing_method() {
+    let mut timer = timer();
+    for method in TimingMethod::all() {
+        assert_eq!(method.other().other(), method);
+        assert_ne!(method.other(), method);
+    }
+
+    timer.set_current_timing_method(TimingMethod::RealTime);
+    timer.toggle_timing_method();
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+    timer.toggle_timing_method();
+    assert_eq!(timer.current_timing_method(), TimingMethod::RealTime);
+}
+
+#[test]
+fn partial_improvements() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(2.0)]);
+    assert_eq!(timer.partial_improvements(TimingMethod::GameTime), [0]);
+    timer.reset(true);
+
+    run_with_splits(&mut timer, &[2.0, 4.0, 6.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.5), Some(4.0)]);
+    assert_eq!(timer.partial_improvements(TimingMethod::GameTime), [0]);
+
+    timer.undo_split();
+    make_progress_run_with_splits_opt(&mut timer, &[Some(3.0)]);
+    assert_eq!(timer.partial_improvements(TimingMethod::GameTime), [0, 1]);
+
+    timer.reset(true);
+    assert!(timer
+        .partial_improvements(TimingMethod::GameTime)
+        .is_empty());
+    assert_eq!(
+        timer.run().segment(1).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(1.5))
+    );
+}
+
+#[test]
+fn finish_detection() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    assert!(!timer.poll_finish());
+
+    timer.set_finish_detection(Some(TimeSpan::zero()));
+    timer.set_game_time(TimeSpan::from_seconds(1.0));
+    assert!(!timer.poll_finish());
+    assert!(!timer.poll_finish());
+    assert_eq!(timer.current_split_index(), Some(0));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), Some(2.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(3.0));
+    assert!(!timer.poll_finish());
+    timer.set_game_time(TimeSpan::from_seconds(3.5));
+    assert!(!timer.poll_finish());
+    assert!(timer.poll_finish());
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        timer.run().segment(2).split_time().game_time,
+        Some(TimeSpan::from_seconds(3.5))
+    );
+    assert!(!timer.poll_finish());
+
+    timer.undo_split();
+    timer.set_finish_detection(Some(TimeSpan::from_seconds(60.0)));
+    assert!(!timer.poll_finish());
+    assert!(!timer.poll_finish());
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+}
+
+#[test]
+fn generator_comparisons_are_distinguished_from_custom_ones() {
+    let mut run = run();
+    run.custom_comparisons_mut().push("Custom".into());
+    let timer = Timer::new(run).unwrap();
+
+    assert!(timer.is_generator_comparison(personal_best::NAME));
+    assert!(timer.is_generator_comparison(crate::comparison::best_segments::NAME));
+    assert!(timer.is_generator_comparison(crate::comparison::average_segments::NAME));
+    assert!(!timer.is_generator_comparison("Custom"));
+    assert!(!timer.is_generator_comparison("Unknown"));
+
+    for comparison in timer.run().comparisons() {
+        assert_eq!(
+            timer.is_generator_comparison(comparison),
+            comparison != "Custom"
+        );
+    }
+}
+
+#[test]
+fn progress_ratio_is_relative_to_the_comparison_final_time() {
+    let mut timer = timer();
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.progress_ratio(&timer.snapshot(), TimingMethod::GameTime),
+        None
+    );
+    timer.reset(false);
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 8.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+    assert_eq!(
+        timer.progress_ratio(&timer.snapshot(), TimingMethod::GameTime),
+        Some(0.25)
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(10.0));
+    assert_eq!(
+        timer.progress_ratio(&timer.snapshot(), TimingMethod::GameTime),
+        Some(1.0)
+    );
+}
+
+#[test]
+fn set_game_time_at_correlates_with_the_provided_real_time() {
+    let mut run = run();
+    run.set_offset(TimeSpan::from_seconds(10.0));
+    let mut timer = Timer::new(run).unwrap();
+    timer.use_utc(false);
+    timer.start();
+    timer.initialize_game_time();
+
+    // The sample was taken at 8 seconds of Real Time, so 1 second of loading
+    // happened up to that point, even though the Real Time is at 10 seconds
+    // by now.
+    timer.set_game_time_at(TimeSpan::from_seconds(7.0), TimeSpan::from_seconds(8.0));
+    assert_eq!(timer.loading_times(), TimeSpan::from_seconds(1.0));
+
+    let time = timer.current_time();
+    assert_eq!(
+        time.real_time.unwrap() - time.game_time.unwrap(),
+        TimeSpan::from_seconds(1.0)
+    );
+}
+
+#[test]
+fn split_index_at_time_maps_times_to_segments() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+    timer.split();
+    timer.skip_split();
+    timer.set_game_time(TimeSpan::from_seconds(6.0));
+    timer.split();
+
+    let at = |t| timer.split_index_at_time(TimeSpan::from_seconds(t), TimingMethod::GameTime);
+    assert_eq!(at(-1.0), None);
+    assert_eq!(at(0.0), Some(0));
+    assert_eq!(at(1.5), Some(0));
+    assert_eq!(at(2.0), Some(2));
+    assert_eq!(at(5.0), Some(2));
+    assert_eq!(at(6.0), None);
+    assert_eq!(at(10.0), None);
+}
+
+#[test]
+fn invalid_attempts_can_be_excluded_from_statistics() {
+    let mut timer = timer();
+    assert!(!timer.mark_last_attempt_invalid("Nothing to mark"));
+
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+    run_with_splits(&mut timer, &[0.5, 4.0, 7.0]);
+    assert!(timer.mark_last_attempt_invalid("Wrong category"));
+
+    let last = timer.run().attempt_history().last().unwrap();
+    assert!(last.is_invalid());
+    assert_eq!(last.invalid_reason(), Some("Wrong category"));
+    assert!(!timer.run().attempt_history()[0].is_invalid());
+
+    let sum_of_best = |run: &Run| {
+        analysis::sum_of_segments::calculate_best(
+            run.segments(),
+            false,
+            false,
+            TimingMethod::GameTime,
+        )
+    };
+    assert_eq!(sum_of_best(timer.run()), Some(TimeSpan::from_seconds(5.5)));
+    let valid = timer.run().without_invalid_attempts();
+    assert_eq!(sum_of_best(&valid), Some(TimeSpan::from_seconds(6.0)));
+    assert_eq!(valid.attempt_history().len(), 2);
+
+    let session = timer.save_session().unwrap();
+    let timer = Timer::load_session(&session).unwrap();
+    assert_eq!(
+        timer
+            .run()
+            .attempt_history()
+            .last()
+            .unwrap()
+            .invalid_reason(),
+        Some("Wrong category")
+    );
+}
+
+#[test]
+fn setters_can_be_chained() {
+    let mut timer = timer();
+    timer
+        .with_utc(false)
+        .with_timing_method(TimingMethod::GameTime)
+        .with_display_method(Some(TimingMethod::RealTime))
+        .try_with_comparison(crate::comparison::best_segments::NAME)
+        .unwrap();
+
+    assert!(!timer.use_utc);
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+    assert_eq!(timer.display_method(), TimingMethod::RealTime);
+    assert_eq!(
+        timer.current_comparison(),
+        crate::comparison::best_segments::NAME
+    );
+
+    assert!(timer.try_with_comparison("Doesn't exist").is_err());
+    assert_eq!(
+        timer.current_comparison(),
+        crate::comparison::best_segments::NAME
+    );
+}
+
+#[test]
+fn replaying_the_action_log_reproduces_the_attempt() {
+    let wait = || std::thread::sleep(std::time::Duration::from_millis(20));
+
+    let mut timer = timer();
+    timer.enable_action_log();
+    timer.start();
+    wait();
+    timer.split();
+    wait();
+    timer.pause();
+    wait();
+    timer.resume();
+    timer.skip_split();
+    wait();
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    let replayed = Timer::replay(timer.action_log(), run()).unwrap();
+    assert_eq!(replayed.current_phase(), TimerPhase::Ended);
+
+    for (original, replayed) in timer.run().segments().iter().zip(replayed.run().segments()) {
+        match (
+            original.split_time().real_time,
+            replayed.split_time().real_time,
+        ) {
+            (Some(original), Some(replayed)) => {
+                assert!((original - replayed).total_seconds().abs() < 0.01);
+            }
+            (original, replayed) => assert_eq!(original, replayed),
+        }
+    }
+    assert!(replayed.run().segment(0).split_time().real_time.is_some());
+    assert!(replayed.run().segment(1).split_time().real_time.is_none());
+}
+
+#[test]
+fn replaying_the_action_log_reproduces_the_kind_of_reset() {
+    let mut timer = timer();
+    timer.enable_action_log();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(0.5));
+    timer.split();
+    timer.reset_with(ResetKind::SaveHistoryOnly);
+
+    timer.start_from_split(1).unwrap();
+    timer.split();
+    timer.reset(true);
+
+    let actions: Vec<_> = timer.action_log().iter().map(|&(_, a)| a).collect();
+    assert!(actions.contains(&LoggedAction::Reset(ResetKind::SaveHistoryOnly)));
+    assert!(actions.contains(&LoggedAction::StartFromSplit(1)));
+    assert_eq!(
+        actions.last(),
+        Some(&LoggedAction::Reset(ResetKind::Discard))
+    );
+
+    let replayed = Timer::replay(timer.action_log(), run()).unwrap();
+    assert_eq!(replayed.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(replayed.run().attempt_history().len(), 2);
+    assert_eq!(
+        replayed
+            .run()
+            .segment(0)
+            .personal_best_split_time()
+            .game_time,
+        Some(TimeSpan::from_seconds(1.0))
+    );
+    assert_eq!(
+        replayed.run().segment(0).segment_history().iter().count(),
+        2
+    );
+    assert_eq!(
+        replayed.run().segment(1).segment_history().iter().count(),
+        1
+    );
+}
+
+#[test]
+fn replaying_the_action_log_reproduces_the_game_time() {
+    let mut timer = timer();
+    timer.enable_action_log();
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(1.0));
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(2.5));
+    timer.split();
+    timer.set_game_time(TimeSpan::from_seconds(3.0));
+    timer.split();
+    timer.reset(true);
+
+    // Only the offsets from the start of each attempt get recorded, so the
+    // second attempt is replayed the same way, no matter when it happened.
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(4.0));
+    timer.split();
+
+    let replayed = Timer::replay(timer.action_log(), run()).unwrap();
+    assert_eq!(replayed.run().attempt_history().len(), 1);
+    assert_eq!(
+        replayed
+            .run()
+            .segment(1)
+            .personal_best_split_time()
+            .game_time,
+        Some(TimeSpan::from_seconds(2.5))
+    );
+    assert_eq!(replayed.current_phase(), TimerPhase::Running);
+    assert_eq!(
+        replayed.run().segment(0).split_time().game_time,
+        Some(TimeSpan::from_seconds(4.0))
+    );
+}
+
+#[test]
+fn estimated_time_remaining_counts_down_to_the_final_time() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[1.0, 3.0, 6.0]);
+
+    assert_eq!(
+        timer.estimated_time_remaining(&timer.snapshot(), TimingMethod::GameTime),
+        None
+    );
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(2.0));
+    assert_eq!(
+        timer.estimated_time_remaining(&timer.snapshot(), TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(4.0))
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(7.5));
+    assert_eq!(
+        timer.estimated_time_remaining(&timer.snapshot(), TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(-1.5))
+    );
+}
+
+#[test]
+fn attempt_comparisons_are_kept() {
+    let mut timer = timer();
+    timer.start();
+    timer.reset(true);
+
+    timer
+        .set_current_comparison(crate::comparison::average_segments::NAME)
+        .unwrap();
+    timer.start();
+    timer.reset(true);
+
+    let indices: Vec<_> = timer
+        .run()
+        .attempt_history()
+        .iter()
+        .map(|a| a.index())
+        .collect();
+    assert_eq!(
+        timer.attempt_comparison(indices[0]),
+        Some(personal_best::NAME)
+    );
+    assert_eq!(
+        timer.attempt_comparison(indices[1]),
+        Some(crate::comparison::average_segments::NAME)
+    );
+    assert_eq!(timer.attempt_comparison(indices[1] + 1), None);
+
+    let session = timer.save_session().unwrap();
+    let timer = Timer::load_session(&session).unwrap();
+    assert_eq!(
+        timer.attempt_comparison(indices[1]),
+        Some(crate::comparison::average_segments::NAME)
+    );
+}
+
+#[test]
+fn game_time_pause_can_be_toggled() {
+    let mut timer = timer();
+    timer.start();
+    timer.initialize_game_time();
+    assert!(!timer.is_game_time_paused());
+
+    timer.toggle_game_time_pause();
+    assert!(timer.is_game_time_paused());
+
+    timer.toggle_game_time_pause();
+    assert!(!timer.is_game_time_paused());
+}
+
+#[test]
+fn split_datetimes_are_tracked() {
+    let mut timer = timer();
+    timer.start();
+    let before = crate::AtomicDateTime::now();
+    timer.split();
+    let after = crate::AtomicDateTime::now();
+    timer.skip_split();
+
+    let first = timer.split_datetime_utc(0).unwrap();
+    assert!(first.time >= before.time && first.time <= after.time);
+    assert_eq!(timer.split_datetime_utc(1), None);
+    assert_eq!(timer.split_datetime_utc(2), None);
+
+    let state = timer.timer_state(Action::None);
+    let mut restored = Timer::new(run()).unwrap();
+    restored.replace_state(&state).unwrap();
+    assert!(restored.split_datetime_utc(0).is_some());
+
+    timer.undo_split();
+    timer.undo_split();
+    assert_eq!(timer.split_datetime_utc(0), None);
+
+    timer.split();
+    timer.reset(false);
+    assert_eq!(timer.split_datetime_utc(0), None);
+}
+
+#[test]
+fn external_time_drives_the_real_time() {
+    let mut timer = timer();
+    timer.set_external_time(Some(TimeSpan::from_seconds(5.0)));
+    assert_eq!(timer.current_time().real_time, Some(TimeSpan::zero()));
+
+    timer.start();
+    assert_eq!(
+        timer.current_time().real_time,
+        Some(TimeSpan::from_seconds(5.0))
+    );
+
+    timer.set_external_time(Some(TimeSpan::from_seconds(7.5)));
+    timer.split();
+    assert_eq!(
+        timer.run().segment(0).split_time().real_time,
+        Some(TimeSpan::from_seconds(7.5))
+    );
+
+    timer.pause();
+    timer.set_external_time(Some(TimeSpan::from_seconds(9.0)));
+    assert_eq!(
+        timer.current_time().real_time,
+        Some(TimeSpan::from_seconds(7.5))
+    );
+    timer.resume();
+    assert_eq!(
+        timer.current_time().real_time,
+        Some(TimeSpan::from_seconds(9.0))
+    );
+
+    timer.set_external_time(None);
+    assert_eq!(timer.external_time(), None);
+    let real_time = timer.current_time().real_time.unwrap();
+    assert!(real_time >= TimeSpan::from_seconds(9.0));
+    assert!(real_time < TimeSpan::from_seconds(10.0));
+}
+
+#[test]
+fn clearing_external_time_while_paused_continues_from_pause() {
+    let mut timer = timer();
+    timer.set_external_time(Some(TimeSpan::from_seconds(5.0)));
+    timer.start();
+
+    timer.set_external_time(Some(TimeSpan::from_seconds(60.0)));
+    timer.pause();
+    timer.set_external_time(Some(TimeSpan::from_seconds(90.0)));
+    timer.set_external_time(None);
+    assert_eq!(
+        timer.current_time().real_time,
+        Some(TimeSpan::from_seconds(60.0))
+    );
+
+    timer.resume();
+    let real_time = timer.current_time().real_time.unwrap();
+    assert!(real_time >= TimeSpan::from_seconds(60.0));
+    assert!(real_time < TimeSpan::from_seconds(61.0));
+}
+
+#[test]
+fn skipped_segments_exclude_unreached_ones() {
+    let mut timer = timer();
+    assert_eq!(timer.skipped_segments(), Vec::<usize>::new());
+
+    timer.start();
+    timer.skip_split();
+    assert_eq!(timer.skipped_segments(), [0]);
+
+    timer.split();
+    assert_eq!(timer.skipped_segments(), [0]);
+
+    timer.split();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.skipped_segments(), [0]);
+
+    timer.reset(false);
+    assert_eq!(timer.skipped_segments(), Vec::<usize>::new());
+}
+
+#[test]
+fn elapsed_since_last_action() {
+    let mut timer = timer();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert_eq!(timer.elapsed_since_last_action(), TimeSpan::zero());
+
+    timer.start();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    let elapsed = timer.elapsed_since_last_action();
+    assert!(elapsed >= TimeSpan::from_seconds(0.01));
+
+    timer.split();
+    assert!(timer.elapsed_since_last_action() < elapsed);
+}
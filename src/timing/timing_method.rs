@@ -19,4 +19,12 @@ impl TimingMethod {
     pub const fn all() -> [TimingMethod; 2] {
         [TimingMethod::RealTime, TimingMethod::GameTime]
     }
+
+    /// Returns the timing method that isn't this one.
+    pub const fn other(self) -> TimingMethod {
+        match self {
+            TimingMethod::RealTime => TimingMethod::GameTime,
+            TimingMethod::GameTime => TimingMethod::RealTime,
+        }
+    }
 }
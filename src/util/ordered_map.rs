@@ -61,6 +61,12 @@ impl<V> Map<V> {
         }
     }
 
+    /// Retain only the key-value pairs for which the predicate returns `true`,
+    /// preserving their order.
+    pub fn retain(&mut self, mut f: impl FnMut(&str, &mut V) -> bool) {
+        self.0.retain_mut(|(k, v)| f(k, v));
+    }
+
     /// Return an iterator over the key-value pairs of the [`Map`], in their order.
     pub fn iter(&self) -> Iter<'_, V> {
         Iter(self.0.iter())
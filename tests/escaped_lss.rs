@@ -1,6 +1,6 @@
 use livesplit_core::{
-    run::{parser, saver},
-    Run, Segment,
+    run::{parser, saver, PauseInterval},
+    AtomicDateTime, Run, Segment, Time,
 };
 
 #[test]
@@ -29,3 +29,43 @@ fn escaping_works_for_auto_splitter_settings() {
     run = parser::livesplit::parse(&buf).unwrap();
     assert_eq!(run.auto_splitter_settings(), "<Hi>A &lt; B</Hi>");
 }
+
+#[test]
+fn escaping_works_for_attempt_metadata() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.add_attempt(Time::new(), None, None, None);
+    run.attempt_history_mut()[0]
+        .metadata_mut()
+        .insert("Seed".into(), "<1234>".into());
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(buf.contains("&lt;1234&gt;"));
+
+    run = parser::livesplit::parse(&buf).unwrap();
+    let metadata = run.attempt_history()[0].metadata().unwrap();
+    assert_eq!(metadata.len(), 1);
+    assert_eq!(metadata["Seed"], "<1234>");
+}
+
+#[test]
+fn escaping_works_for_pause_interval_labels() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.add_attempt(Time::new(), None, None, None);
+    let started = AtomicDateTime::now();
+    run.attempt_history_mut()[0]
+        .pause_intervals_mut()
+        .push(PauseInterval::new("<Loading>", started, None));
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(buf.contains("&lt;Loading&gt;"));
+
+    run = parser::livesplit::parse(&buf).unwrap();
+    let intervals = run.attempt_history()[0].pause_intervals();
+    assert_eq!(intervals.len(), 1);
+    assert_eq!(intervals[0].label(), "<Loading>");
+    assert_eq!(intervals[0].ended(), None);
+}
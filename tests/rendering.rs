@@ -8,9 +8,9 @@ mod tests_helper;
 use image::Rgba;
 use img_hash::{HasherConfig, ImageHash};
 use livesplit_core::{
-    component::{self, timer},
+    component::{self, splits, timer},
     layout::{self, Component, ComponentState, Layout, LayoutDirection, LayoutState},
-    rendering::software::Renderer,
+    rendering::software::{render_to_buffer, Renderer},
     run::parser::{livesplit, llanfair, wsplit},
     Run, Segment, TimeSpan, Timer, TimingMethod,
 };
@@ -41,6 +41,78 @@ fn default() {
     check(&state, "luoAAABANDM=", "default");
 }
 
+#[test]
+fn render_to_buffer_matches_renderer() {
+    let run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    let state = layout.state(&timer.snapshot());
+
+    let buffer = render_to_buffer(&state, 300, 500);
+    assert_eq!(buffer.len(), 300 * 500 * 4);
+
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [300, 500]);
+    assert_eq!(buffer, renderer.image_data());
+}
+
+#[test]
+fn component_bounds() {
+    let run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut state = layout.state(&timer.snapshot());
+
+    let mut renderer = Renderer::new();
+    let mut bounds = Vec::new();
+    renderer.render_with_bounds(&state, [300, 500], &mut bounds);
+    assert_eq!(bounds.len(), state.components.len());
+
+    let mut y = 0.0;
+    for (i, &(index, [x, top, width, height])) in bounds.iter().enumerate() {
+        assert_eq!(index, i);
+        assert_eq!(x, 0.0);
+        assert!((top - y).abs() < 0.01);
+        assert!((width - 300.0).abs() < 0.01);
+        y += height;
+    }
+    assert!((y - 500.0).abs() < 0.01);
+
+    state.direction = LayoutDirection::Horizontal;
+    bounds.clear();
+    renderer.render_with_bounds(&state, [1200, 40], &mut bounds);
+    let mut x = 0.0;
+    for &(_, [left, top, width, height]) in &bounds {
+        assert!((left - x).abs() < 0.01);
+        assert_eq!(top, 0.0);
+        assert!((height - 40.0).abs() < 0.01);
+        x += width;
+    }
+    assert!((x - 1200.0).abs() < 0.01);
+}
+
+#[test]
+fn unchanged_frames_are_detected() {
+    let run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    let mut renderer = Renderer::new();
+    renderer.render(&layout.state(&timer.snapshot()), [300, 500]);
+    assert!(renderer.frame_changed());
+
+    renderer.render(&layout.state(&timer.snapshot()), [300, 500]);
+    assert!(!renderer.frame_changed());
+
+    timer.start();
+    timer.split();
+    renderer.render(&layout.state(&timer.snapshot()), [300, 500]);
+    assert!(renderer.frame_changed());
+
+    renderer.render(&layout.state(&timer.snapshot()), [300, 400]);
+    assert!(renderer.frame_changed());
+}
+
 #[test]
 fn actual_split_file() {
     let run = lss(run_files::LIVESPLIT_1_0);
@@ -243,6 +315,58 @@ fn horizontal() {
     );
 }
 
+#[test]
+fn horizontal_fixed_width() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+    match &mut layout.components[1] {
+        Component::Splits(splits) => splits.settings_mut().visual_split_count = 1,
+        _ => unreachable!("We wanted to configure the splits"),
+    }
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(20.0)]);
+
+    let mut state = layout.state(&timer.snapshot());
+    state.fixed_component_width = Some(8.0);
+
+    check_dims(&state, [1200, 40], "6TJyMqqoqFI=", "horizontal_fixed_width");
+}
+
+#[test]
+fn horizontal_possible_time_save_column() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+    match &mut layout.components[1] {
+        Component::Splits(splits) => {
+            let settings = splits.settings_mut();
+            settings.visual_split_count = 2;
+            settings.columns.push(splits::ColumnSettings {
+                name: String::from("Possible Time Save"),
+                kind: splits::ColumnKind::Time(splits::TimeColumn {
+                    start_with: splits::ColumnStartWith::PossibleTimeSave,
+                    update_with: splits::ColumnUpdateWith::DontUpdate,
+                    ..Default::default()
+                }),
+            });
+        }
+        _ => unreachable!("We wanted to configure the splits"),
+    }
+
+    tests_helper::start_run(&mut timer);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [1500, 40],
+        "1MjoYUVUVqM=",
+        "horizontal_possible_time_save_column",
+    );
+}
+
 fn get_comparison_tolerance() -> u32 {
     // Without MMX the floating point calculations don't follow IEEE 754, so the tests require a
     // tolerance that is greater than 0.